@@ -31,19 +31,14 @@ fn main() {
         .run();
 }
 
-#[derive(Clone, States, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, States, PartialEq, Eq, Debug, Hash, Default)]
 enum GameState {
     Game,
+    #[default]
     Menu,
     Done,
 }
 
-impl Default for GameState {
-    fn default() -> Self {
-        GameState::Menu
-    }
-}
-
 fn setup(mut commands: Commands, mut leaderboard: ResMut<Leaderboard>) {
     commands.spawn(Camera2dBundle::default());
     leaderboard.create_player(None);
@@ -56,7 +51,7 @@ mod menu {
         prelude::*,
         winit::{UpdateMode, WinitSettings},
     };
-    use bevy_jornet::Leaderboard;
+    use bevy_jornet::{Leaderboard, ScoreSubmitted};
 
     use crate::{GameState, BACKGROUND, BUTTON, TEXT};
     pub struct MenuPlugin;
@@ -65,6 +60,7 @@ mod menu {
         fn build(&self, app: &mut App) {
             app.add_system(display_menu.in_schedule(OnEnter(GameState::Menu)))
                 .add_systems((button_system, display_scores).in_set(OnUpdate(GameState::Menu)))
+                .add_system(refresh_on_score)
                 .add_system(despawn_menu.in_schedule(OnExit(GameState::Menu)));
         }
     }
@@ -213,6 +209,13 @@ mod menu {
         leaderboard.refresh_leaderboard();
     }
 
+    fn refresh_on_score(mut submitted: EventReader<ScoreSubmitted>, leaderboard: Res<Leaderboard>) {
+        // the score is sent when leaving the game, it may not be in the leaderboard shown yet
+        if submitted.iter().count() > 0 {
+            leaderboard.refresh_leaderboard();
+        }
+    }
+
     #[derive(Component)]
     struct PlayerName;
 
@@ -265,6 +268,7 @@ mod menu {
         }
     }
 
+    #[allow(clippy::type_complexity)]
     fn button_system(
         mut interaction_query: Query<
             (&Interaction, &mut BackgroundColor),
@@ -306,7 +310,7 @@ mod game {
         window::PrimaryWindow,
         winit::{UpdateMode, WinitSettings},
     };
-    use bevy_jornet::Leaderboard;
+    use bevy_jornet::JornetCommandsExt;
     use rand::Rng;
 
     use crate::{GameState, GameStatus, SQUARE, TEXT};
@@ -468,7 +472,6 @@ mod game {
 
     fn save_score(
         status: Res<GameStatus>,
-        leaderboard: Res<Leaderboard>,
         mut commands: Commands,
         game_ui: Query<Entity, With<Node>>,
         squares: Query<Entity, With<Sprite>>,
@@ -479,7 +482,7 @@ mod game {
         for entity in &squares {
             commands.entity(entity).despawn();
         }
-        leaderboard.send_score(status.score as f32);
+        commands.jornet().send_score(status.score as f32);
     }
 }
 
@@ -0,0 +1,80 @@
+use bevy::{
+    ecs::system::Commands,
+    prelude::{Mut, World},
+};
+
+use crate::{JornetError, Leaderboard};
+
+/// Extension trait adding [`Self::jornet`] to [`Commands`], to interact with the [`Leaderboard`]
+/// from systems that don't otherwise need it.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// use bevy_jornet::JornetCommandsExt;
+///
+/// fn game_over(mut commands: Commands) {
+///     commands.jornet().send_score(42.0);
+/// }
+/// ```
+pub trait JornetCommandsExt<'w, 's> {
+    /// Get the commands interacting with the [`Leaderboard`].
+    fn jornet<'a>(&'a mut self) -> JornetCommands<'w, 's, 'a>;
+}
+
+impl<'w, 's> JornetCommandsExt<'w, 's> for Commands<'w, 's> {
+    fn jornet<'a>(&'a mut self) -> JornetCommands<'w, 's, 'a> {
+        JornetCommands { commands: self }
+    }
+}
+
+/// Commands interacting with the [`Leaderboard`], returned by [`JornetCommandsExt::jornet`].
+/// Like other commands, they are applied at the end of the stage, and the requests then run in
+/// the background. The same events as the methods of the [`Leaderboard`] are sent once they
+/// finish, or [`JornetError`] if they failed or the [`Leaderboard`] resource doesn't exist yet.
+pub struct JornetCommands<'w, 's, 'a> {
+    commands: &'a mut Commands<'w, 's>,
+}
+
+impl<'w, 's, 'a> JornetCommands<'w, 's, 'a> {
+    /// Send a score to the leaderboard, as the current player.
+    /// [`ScoreSubmitted`](crate::ScoreSubmitted) is sent once the server received it.
+    pub fn send_score(&mut self, score: f32) -> &mut Self {
+        self.commands.add(move |world: &mut World| {
+            with_leaderboard(world, |leaderboard| leaderboard.send_score(score));
+        });
+        self
+    }
+
+    /// Create a player. If you don't specify a name, one will be generated randomly.
+    /// [`PlayerCreated`](crate::PlayerCreated) is sent once the server answered.
+    pub fn create_player(&mut self, name: Option<&str>) -> &mut Self {
+        let name = name.map(str::to_string);
+        self.commands.add(move |world: &mut World| {
+            with_leaderboard(world, |mut leaderboard| {
+                leaderboard.create_player(name.as_deref())
+            });
+        });
+        self
+    }
+
+    /// Refresh the leaderboard.
+    /// [`LeaderboardRefreshed`](crate::LeaderboardRefreshed) is sent once the scores were
+    /// received.
+    pub fn refresh_leaderboard(&mut self) -> &mut Self {
+        self.commands.add(|world: &mut World| {
+            with_leaderboard(world, |leaderboard| leaderboard.refresh_leaderboard());
+        });
+        self
+    }
+}
+
+/// Apply `command` to the [`Leaderboard`], or send a [`JornetError`] if there is none, like
+/// before its configuration is loaded or without the plugin.
+fn with_leaderboard(world: &mut World, command: impl FnOnce(Mut<Leaderboard>)) {
+    match world.get_resource_mut::<Leaderboard>() {
+        Some(leaderboard) => command(leaderboard),
+        None => world.send_event(JornetError(jornet::JornetError::InvalidConfiguration(
+            "the leaderboard is not ready".to_string(),
+        ))),
+    }
+}
@@ -0,0 +1,27 @@
+use bevy::prelude::{EventReader, Res};
+
+use crate::{Leaderboard, LeaderboardRefreshed};
+
+/// Run condition that is `true` when new scores of the [`Leaderboard`] were received since the
+/// system last ran, to rebuild what shows them only then.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_jornet::{leaderboard_changed, Leaderboard};
+/// fn display_scores(leaderboard: Res<Leaderboard>) {
+///     // ...
+/// }
+///
+/// App::new().add_system(display_scores.run_if(leaderboard_changed));
+/// ```
+pub fn leaderboard_changed(mut refreshed: EventReader<LeaderboardRefreshed>) -> bool {
+    // events are consumed so that the condition isn't true again for the same refresh
+    refreshed.iter().count() > 0
+}
+
+/// Run condition that is `true` when a player was created or connected on the [`Leaderboard`],
+/// so that scores can be sent.
+pub fn player_connected(leaderboard: Option<Res<Leaderboard>>) -> bool {
+    // the leaderboard doesn't exist until its configuration is loaded with the `config` feature
+    leaderboard.is_some_and(|leaderboard| leaderboard.get_player().is_some())
+}
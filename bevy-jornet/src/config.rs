@@ -0,0 +1,105 @@
+use bevy::{
+    asset::{AssetLoader, AssetServer, Assets, Handle, LoadContext, LoadState, LoadedAsset},
+    prelude::{Commands, Res, ResMut, Resource},
+    reflect::TypeUuid,
+    utils::BoxedFuture,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::Leaderboard;
+
+/// Credentials of a leaderboard, loaded from a `.jornet.ron` or `.jornet.toml` asset by
+/// [`JornetPlugin::with_config`](crate::JornetPlugin::with_config).
+///
+/// In RON:
+///
+/// ```ron
+/// (
+///     id: "fb0bbe22-b047-494d-9519-1d36668fa5bc",
+///     key: "6c3a9e1c-2d1a-4e1f-8e3c-3b1a0e7c1d2f",
+///     // optional, to use another host than https://jornet.vleue.com
+///     host: Some("https://staging.example.com"),
+/// )
+/// ```
+///
+/// In TOML:
+///
+/// ```toml
+/// id = "fb0bbe22-b047-494d-9519-1d36668fa5bc"
+/// key = "6c3a9e1c-2d1a-4e1f-8e3c-3b1a0e7c1d2f"
+/// host = "https://staging.example.com"
+/// ```
+#[derive(Deserialize, TypeUuid, Debug, Clone)]
+#[uuid = "7cb93ce4-f485-4a9e-aa43-3d508d06c98f"]
+pub struct JornetConfig {
+    /// ID of the leaderboard.
+    pub id: Uuid,
+    /// Key of the leaderboard.
+    pub key: Uuid,
+    /// Host of the server, instead of the one set on the plugin.
+    #[serde(default)]
+    pub host: Option<String>,
+}
+
+/// Loader of [`JornetConfig`] assets, in RON or TOML depending on their extension.
+#[derive(Default)]
+pub struct JornetConfigLoader;
+
+impl AssetLoader for JornetConfigLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let config: JornetConfig = match load_context.path().extension() {
+                Some(extension) if extension == "toml" => {
+                    toml::from_str(std::str::from_utf8(bytes)?)?
+                }
+                _ => ron::de::from_bytes(bytes)?,
+            };
+            load_context.set_default_asset(LoadedAsset::new(config));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["jornet.ron", "jornet.toml"]
+    }
+}
+
+/// Configuration asset the [`Leaderboard`] resource is waiting for, with the options of the
+/// plugin to create it.
+#[derive(Resource)]
+pub(crate) struct PendingConfig {
+    pub(crate) path: String,
+    pub(crate) handle: Handle<JornetConfig>,
+    pub(crate) host: Option<String>,
+    #[cfg(feature = "persistence")]
+    pub(crate) saved_player: Option<String>,
+}
+
+pub(crate) fn load_config(mut pending: ResMut<PendingConfig>, assets: Res<AssetServer>) {
+    pending.handle = assets.load(pending.path.as_str());
+}
+
+/// Insert the [`Leaderboard`] resource once its configuration is loaded.
+pub(crate) fn init_from_config(
+    mut commands: Commands,
+    pending: Res<PendingConfig>,
+    configs: Res<Assets<JornetConfig>>,
+    assets: Res<AssetServer>,
+) {
+    if let Some(config) = configs.get(&pending.handle) {
+        let host = config.host.clone().or_else(|| pending.host.clone());
+        let leaderboard = Leaderboard::new(host, config.id, config.key);
+        #[cfg(feature = "persistence")]
+        let leaderboard = leaderboard.saving_player_at(pending.saved_player.as_deref());
+        commands.insert_resource(leaderboard);
+        commands.remove_resource::<PendingConfig>();
+    } else if assets.get_load_state(&pending.handle) == LoadState::Failed {
+        // the asset server already logged why
+        commands.remove_resource::<PendingConfig>();
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::VecDeque;
+
+use bevy::{
+    ecs::schedule::ScheduleLabel,
+    prelude::{App, EventReader, IntoSystemConfig, Res, ResMut, Resource},
+};
+use bevy_egui::{egui, EguiContexts, EguiPlugin};
+
+use crate::{add_system_to, JornetError, JornetSystems, Leaderboard};
+
+/// Number of errors kept to be shown in the panel.
+const KEPT_ERRORS: usize = 10;
+
+/// Last errors of the requests, newest first.
+#[derive(Resource, Default)]
+struct LastErrors(VecDeque<String>);
+
+fn record_errors(mut errors: EventReader<JornetError>, mut last: ResMut<LastErrors>) {
+    for JornetError(error) in errors.iter() {
+        last.0.push_front(error.to_string());
+        last.0.truncate(KEPT_ERRORS);
+    }
+}
+
+fn debug_panel(
+    mut contexts: EguiContexts,
+    leaderboard: Option<Res<Leaderboard>>,
+    errors: Res<LastErrors>,
+) {
+    egui::Window::new("Jornet").show(contexts.ctx_mut(), |ui| {
+        let Some(leaderboard) = leaderboard else {
+            ui.label("Waiting for the configuration of the leaderboard");
+            return;
+        };
+
+        ui.label(format!("Leaderboard: {}", leaderboard.id));
+        if let Some(host) = &leaderboard.host {
+            ui.label(format!("Host: {}", host));
+        }
+        match leaderboard.get_player() {
+            Some(player) => ui.label(format!("Player: {} ({})", player.name, player.id)),
+            None => ui.label("Player: none"),
+        };
+        ui.label(format!("Pending scores: {}", leaderboard.pending_scores()));
+        ui.label(format!(
+            "Refreshing: {}{}",
+            leaderboard.is_refreshing(),
+            if leaderboard.refresh_failed() {
+                ", last refresh failed"
+            } else {
+                ""
+            }
+        ));
+
+        ui.collapsing(format!("Last errors ({})", errors.0.len()), |ui| {
+            for error in &errors.0 {
+                ui.label(error);
+            }
+        });
+
+        ui.collapsing(format!("Scores ({})", leaderboard.scores.len()), |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                for score in &leaderboard.scores {
+                    ui.monospace(format!("{:?}", score));
+                }
+            });
+        });
+    });
+}
+
+/// Add the debug panel, and the `EguiPlugin` if it wasn't already. Errors are recorded in
+/// `schedule` with the other systems of the plugin, but the panel is drawn every frame as egui
+/// expects.
+pub(crate) fn add_panel(app: &mut App, schedule: &dyn ScheduleLabel) {
+    if !app.is_plugin_added::<EguiPlugin>() {
+        app.add_plugin(EguiPlugin);
+    }
+    app.init_resource::<LastErrors>().add_system(debug_panel);
+    add_system_to(app, schedule, record_errors.after(JornetSystems::Receive));
+}
@@ -0,0 +1,58 @@
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::{App, EventWriter},
+};
+use uuid::Uuid;
+
+use crate::Player;
+
+/// Sent when a player was created by
+/// [`Leaderboard::create_player`](crate::Leaderboard::create_player). The player is already set
+/// on the [`Leaderboard`](crate::Leaderboard) resource.
+#[derive(Debug, Clone)]
+pub struct PlayerCreated(pub Player);
+
+/// Sent when a player is connected, either created or restored, so that scores can be sent.
+/// See [`player_connected`](crate::player_connected) for a run condition.
+#[derive(Debug, Clone)]
+pub struct PlayerConnected(pub Player);
+
+/// Sent when a score was received by the server.
+#[derive(Debug, Clone, Copy)]
+pub struct ScoreSubmitted {
+    /// ID of the leaderboard the score was sent to.
+    pub leaderboard: Uuid,
+    /// Rank of the score in the leaderboard, starting at 1, if the server sent it.
+    pub rank: Option<u32>,
+}
+
+/// Sent when the scores of the leaderboard were received, after a call to
+/// [`Leaderboard::refresh_leaderboard`](crate::Leaderboard::refresh_leaderboard). They can be
+/// read with [`Leaderboard::get_leaderboard`](crate::Leaderboard::get_leaderboard).
+#[derive(Debug, Clone, Copy)]
+pub struct LeaderboardRefreshed {
+    /// ID of the leaderboard that was refreshed.
+    pub leaderboard: Uuid,
+}
+
+/// Sent when a request to the server failed.
+#[derive(Debug, Clone)]
+pub struct JornetError(pub jornet::JornetError);
+
+/// Writers of all the events of the plugin, used by its systems.
+#[derive(SystemParam)]
+pub struct JornetEvents<'w> {
+    pub(crate) player_created: EventWriter<'w, PlayerCreated>,
+    pub(crate) player_connected: EventWriter<'w, PlayerConnected>,
+    pub(crate) score_submitted: EventWriter<'w, ScoreSubmitted>,
+    pub(crate) leaderboard_refreshed: EventWriter<'w, LeaderboardRefreshed>,
+    pub(crate) errors: EventWriter<'w, JornetError>,
+}
+
+pub(crate) fn add_events(app: &mut App) {
+    app.add_event::<PlayerCreated>()
+        .add_event::<PlayerConnected>()
+        .add_event::<ScoreSubmitted>()
+        .add_event::<LeaderboardRefreshed>()
+        .add_event::<JornetError>();
+}
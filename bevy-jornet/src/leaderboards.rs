@@ -0,0 +1,257 @@
+use std::{collections::HashMap, hash::Hash};
+
+use bevy::{
+    ecs::schedule::{BoxedScheduleLabel, ScheduleLabel},
+    prelude::{App, CoreSchedule, IntoSystemConfig, Plugin, ResMut, Resource},
+};
+use uuid::Uuid;
+
+use crate::{
+    add_system_to, events, events::JornetEvents, InvalidCredentials, JornetSystems, Leaderboard,
+    Player, Score,
+};
+
+/// Bevy Plugin handling communications with several leaderboards of the Jornet server, each
+/// identified by a key of type `K`, like an enum of the levels of the game.
+///
+/// Once the plugin is added, you can use the [`Leaderboards`] resource to interact with them.
+pub struct JornetLeaderboardsPlugin<K> {
+    host: Option<String>,
+    leaderboards: Vec<(K, Uuid, Uuid)>,
+    schedule: BoxedScheduleLabel,
+}
+
+impl<K> JornetLeaderboardsPlugin<K> {
+    /// Setup the plugin without leaderboards, they are added with [`Self::add_leaderboard`].
+    pub fn new() -> Self {
+        Self {
+            host: None,
+            leaderboards: vec![],
+            schedule: Box::new(CoreSchedule::Main),
+        }
+    }
+
+    /// Set the plugin to use another host than <https://jornet.vleue.com>.
+    pub fn with_host(self, host: &str) -> Self {
+        Self {
+            host: Some(host.to_string()),
+            ..self
+        }
+    }
+
+    /// Run the systems of the plugin in `schedule` instead of [`CoreSchedule::Main`], like
+    /// [`JornetPlugin::with_schedule`](crate::JornetPlugin::with_schedule).
+    pub fn with_schedule(self, schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: Box::new(schedule),
+            ..self
+        }
+    }
+
+    /// Add the leaderboard with the `id` and `key`, identified by `handle`. They must be `UUID`
+    /// from an existing leaderboard at <https://jornet.vleue.com>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` or `key` is not a valid `UUID`, see [`Self::try_add_leaderboard`] to
+    /// handle it.
+    pub fn add_leaderboard(self, handle: K, id: &str, key: &str) -> Self {
+        self.try_add_leaderboard(handle, id, key)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Add the leaderboard with the `id` and `key` like [`Self::add_leaderboard`], failing if
+    /// they are not valid `UUID`.
+    pub fn try_add_leaderboard(
+        mut self,
+        handle: K,
+        id: &str,
+        key: &str,
+    ) -> Result<Self, InvalidCredentials> {
+        let (id, key) = InvalidCredentials::parse(id, key)?;
+        self.leaderboards.push((handle, id, key));
+        Ok(self)
+    }
+}
+
+impl<K> Default for JornetLeaderboardsPlugin<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K> Plugin for JornetLeaderboardsPlugin<K>
+where
+    K: Clone + Eq + Hash + Send + Sync + 'static,
+{
+    fn build(&self, app: &mut App) {
+        let boards = self
+            .leaderboards
+            .iter()
+            .map(|(handle, id, key)| {
+                (
+                    handle.clone(),
+                    Leaderboard::new(self.host.clone(), *id, *key),
+                )
+            })
+            .collect();
+        events::add_events(app);
+        #[cfg(feature = "reflect")]
+        crate::register_types(app);
+        app.insert_resource(Leaderboards {
+            boards,
+            first: self.leaderboards.first().map(|(handle, ..)| handle.clone()),
+        });
+        add_system_to(
+            app,
+            &*self.schedule,
+            done_refreshing_leaderboards::<K>.in_set(JornetSystems::Receive),
+        );
+    }
+}
+
+/// Leaderboards resource, used to interact with several Jornet leaderboards sharing the same
+/// player. Each leaderboard works like the [`Leaderboard`] resource, and can be accessed with
+/// [`Self::get`].
+///
+/// The events sent carry the ID of the leaderboard they are about, which
+/// [`Self::handle_of`] maps back to its handle.
+#[derive(Resource)]
+pub struct Leaderboards<K> {
+    boards: HashMap<K, Leaderboard>,
+    /// Leaderboard used to create players.
+    first: Option<K>,
+}
+
+impl<K: Eq + Hash> Leaderboards<K> {
+    /// Get the leaderboard identified by `handle`.
+    pub fn get(&self, handle: &K) -> Option<&Leaderboard> {
+        self.boards.get(handle)
+    }
+
+    /// Get the leaderboard identified by `handle`, mutably.
+    pub fn get_mut(&mut self, handle: &K) -> Option<&mut Leaderboard> {
+        self.boards.get_mut(handle)
+    }
+
+    /// Iterate over the leaderboards and their handles.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &Leaderboard)> {
+        self.boards.iter()
+    }
+
+    /// Get the handle of the leaderboard with the ID `id`, like the one of a
+    /// [`LeaderboardRefreshed`](crate::LeaderboardRefreshed) event.
+    pub fn handle_of(&self, id: Uuid) -> Option<&K> {
+        self.boards
+            .iter()
+            .find(|(_, board)| board.id() == id)
+            .map(|(handle, _)| handle)
+    }
+
+    fn board(&self, handle: &K) -> &Leaderboard {
+        self.boards.get(handle).expect("unknown leaderboard")
+    }
+
+    /// Create a player, shared by all the leaderboards. If you don't specify a name, one will be
+    /// generated randomly. See [`Leaderboard::create_player`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if no leaderboard was added to the plugin.
+    pub fn create_player(&mut self, name: Option<&str>) {
+        let first = self.first.as_ref().expect("no leaderboard");
+        // the first handle is always a key of the boards - can't fail
+        self.boards.get_mut(first).unwrap().create_player(name);
+    }
+
+    /// Connect as a returning player on all the leaderboards.
+    pub fn as_player(&mut self, player: Player) {
+        for board in self.boards.values_mut() {
+            board.as_player(player.clone());
+        }
+    }
+
+    /// Get the current player, if one was created or connected.
+    pub fn get_player(&self) -> Option<&Player> {
+        self.boards.values().find_map(Leaderboard::get_player)
+    }
+
+    /// Send a score to the leaderboard identified by `handle`, as the current player.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no leaderboard identified by `handle`.
+    pub fn send_score(&self, handle: &K, score: f32) {
+        self.board(handle).send_score(score);
+    }
+
+    /// Refresh the leaderboard identified by `handle`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no leaderboard identified by `handle`.
+    pub fn refresh_leaderboard(&self, handle: &K) {
+        self.board(handle).refresh_leaderboard();
+    }
+
+    /// Refresh all the leaderboards.
+    pub fn refresh_all(&self) {
+        for board in self.boards.values() {
+            board.refresh_leaderboard();
+        }
+    }
+
+    /// Whether a refresh of the leaderboard identified by `handle` is in progress.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no leaderboard identified by `handle`.
+    pub fn is_refreshing(&self, handle: &K) -> bool {
+        self.board(handle).is_refreshing()
+    }
+
+    /// Get the scores of the leaderboard identified by `handle`, as received by its last
+    /// refresh.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no leaderboard identified by `handle`.
+    pub fn get_leaderboard(&self, handle: &K) -> Vec<Score> {
+        self.board(handle).get_leaderboard()
+    }
+}
+
+/// System to handle refreshing the [`Leaderboards`] resource when new data is available, and
+/// sending events for the requests that finished. A player connected on one leaderboard is
+/// connected on all of them.
+/// It is automatically added by the [`JornetLeaderboardsPlugin`] in the set
+/// [`JornetSystems::Receive`].
+pub fn done_refreshing_leaderboards<K>(
+    mut leaderboards: ResMut<Leaderboards<K>>,
+    mut events: JornetEvents,
+) where
+    K: Clone + Eq + Hash + Send + Sync + 'static,
+{
+    // taken without marking the resource as changed, as nothing may have finished
+    let finished = leaderboards
+        .boards
+        .iter()
+        .map(|(handle, board)| (handle.clone(), board.take_outcomes()))
+        .filter(|(_, outcomes)| !outcomes.is_empty())
+        .collect::<Vec<_>>();
+    if finished.is_empty() {
+        return;
+    }
+
+    let mut connected = None;
+    for (handle, outcomes) in finished {
+        // handles were just read from the boards - can't fail
+        let board = leaderboards.boards.get_mut(&handle).unwrap();
+        if let Some(player) = board.apply_outcomes(outcomes, &mut events) {
+            connected = Some(player);
+        }
+    }
+    if let Some(player) = connected {
+        leaderboards.as_player(player);
+    }
+}
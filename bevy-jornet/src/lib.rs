@@ -6,16 +6,75 @@
 //! - save high scores
 //! - get a leaderboard
 
-use bevy::prelude::{App, Plugin, ResMut, Resource};
+use std::{
+    fmt,
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+#[cfg(feature = "config")]
+use bevy::asset::AddAsset;
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::tasks::Task;
+use bevy::{
+    ecs::schedule::{BoxedScheduleLabel, ScheduleLabel, Schedules},
+    prelude::{
+        resource_added, resource_exists, resource_exists_and_changed, App, CoreSchedule,
+        IntoSystemConfig, Plugin, Res, ResMut, Resource, SystemSet,
+    },
+    tasks::AsyncComputeTaskPool,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use futures_lite::future;
 use uuid::Uuid;
 
+mod commands;
+mod conditions;
+#[cfg(feature = "config")]
+mod config;
+#[cfg(feature = "debug-egui")]
+mod debug;
+mod events;
+mod leaderboards;
+mod rank;
+mod refresh;
+mod state;
+#[cfg(feature = "ui")]
+mod ui;
+
+pub use commands::{JornetCommands, JornetCommandsExt};
+pub use conditions::{leaderboard_changed, player_connected};
+#[cfg(feature = "config")]
+pub use config::{JornetConfig, JornetConfigLoader};
+use events::JornetEvents;
+pub use events::{
+    JornetError, LeaderboardRefreshed, PlayerConnected, PlayerCreated, ScoreSubmitted,
+};
 pub use jornet::{Player, Score};
+pub use leaderboards::{done_refreshing_leaderboards, JornetLeaderboardsPlugin, Leaderboards};
+pub use rank::{update_local_player_rank, LocalPlayerRank};
+pub use refresh::{auto_refresh_leaderboard, AutoRefresh};
+pub use state::{update_jornet_state, JornetState};
+#[cfg(feature = "ui")]
+pub use ui::{update_leaderboard_widgets, LeaderboardWidget, LeaderboardWidgetBundle};
+
+/// Where players are saved with the `persistence` feature, unless set with
+/// [`JornetPlugin::with_saved_player`].
+#[cfg(feature = "persistence")]
+pub const DEFAULT_SAVED_PLAYER: &str = "jornet-player.json";
 
 /// Bevy Plugin handling communications with the Jornet server.
 pub struct JornetPlugin {
-    leaderboard: Uuid,
-    key: Uuid,
+    credentials: Credentials,
     host: Option<String>,
+    auto_refresh: Option<Duration>,
+    player_name: Option<String>,
+    saved_player: Option<String>,
+    schedule: BoxedScheduleLabel,
 }
 
 impl JornetPlugin {
@@ -25,11 +84,42 @@ impl JornetPlugin {
     /// Once the plugin is added, you can use the [`Leaderboard`] resource to interact with it,
     /// [create a player](Leaderboard::create_player), [send a score](Leaderboard::send_score) or
     /// [retrieve the leaderboard](Leaderboard::get_leaderboard).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` or `key` is not a valid `UUID`, see [`Self::try_with_leaderboard`] to
+    /// handle it.
     pub fn with_leaderboard(id: &str, key: &str) -> Self {
+        Self::try_with_leaderboard(id, key).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Setup the plugin with the `id` and `key` like [`Self::with_leaderboard`], failing if
+    /// they are not valid `UUID`, like when they are read from a configuration file.
+    pub fn try_with_leaderboard(id: &str, key: &str) -> Result<Self, InvalidCredentials> {
+        let (id, key) = InvalidCredentials::parse(id, key)?;
+        Ok(Self::with_credentials(Credentials::Leaderboard { id, key }))
+    }
+
+    /// Setup the plugin with the credentials of the [`JornetConfig`] asset at `path`, which must
+    /// end with `.jornet.ron` or `.jornet.toml`, like `leaderboard.jornet.ron`. It must be added
+    /// after the `AssetPlugin`.
+    ///
+    /// The [`Leaderboard`] resource is inserted once the asset is loaded, and doesn't exist
+    /// before, which the [`resource_exists`](bevy::prelude::resource_exists) run condition can
+    /// wait for. If the asset doesn't set a host, the one of [`Self::with_host`] is used.
+    #[cfg(feature = "config")]
+    pub fn with_config(path: &str) -> Self {
+        Self::with_credentials(Credentials::Config(path.to_string()))
+    }
+
+    fn with_credentials(credentials: Credentials) -> Self {
         Self {
-            leaderboard: Uuid::parse_str(id).expect("invalid leaderboard ID"),
-            key: Uuid::parse_str(key).expect("invalid leaderboard key"),
+            credentials,
             host: None,
+            auto_refresh: None,
+            player_name: None,
+            saved_player: None,
+            schedule: Box::new(CoreSchedule::Main),
         }
     }
 
@@ -40,27 +130,580 @@ impl JornetPlugin {
             ..self
         }
     }
+
+    /// Refresh the leaderboard every `interval` while the app runs, starting on startup. The
+    /// [`AutoRefresh`] resource can be used to pause it.
+    pub fn with_auto_refresh(self, interval: Duration) -> Self {
+        Self {
+            auto_refresh: Some(interval),
+            ..self
+        }
+    }
+
+    /// Create a player named `name` on startup, or restore it if it's saved with
+    /// [`Self::with_saved_player`]. [`PlayerConnected`] is sent once the player is ready, which
+    /// the [`player_connected`] run condition can wait for.
+    pub fn with_player_name(self, name: &str) -> Self {
+        Self {
+            player_name: Some(name.to_string()),
+            ..self
+        }
+    }
+
+    /// Restore the player saved at `location` on startup, or create one and save it there, with
+    /// the name set by [`Self::with_player_name`] or a random one. See
+    /// [`Leaderboard::connect_or_create`].
+    ///
+    /// With the `persistence` feature, this only changes where players are saved, which is
+    /// [`DEFAULT_SAVED_PLAYER`] by default, and a player is created on startup only if
+    /// [`Self::with_player_name`] is set.
+    pub fn with_saved_player(self, location: &str) -> Self {
+        Self {
+            saved_player: Some(location.to_string()),
+            ..self
+        }
+    }
+
+    /// Run the systems of the plugin in `schedule` instead of [`CoreSchedule::Main`]. They are
+    /// in the [`JornetSystems`] sets, to order other systems around them.
+    ///
+    /// The configuration is still loaded at startup, and the debug panel of the `debug-egui`
+    /// feature is still drawn every frame.
+    pub fn with_schedule(self, schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: Box::new(schedule),
+            ..self
+        }
+    }
+}
+
+/// Sets of the systems added by the plugins, in the schedule set with
+/// [`JornetPlugin::with_schedule`].
+///
+/// Systems reading the events of the plugin or the scores of the [`Leaderboard`] can run after
+/// [`JornetSystems::Receive`] to get them in the same frame.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JornetSystems {
+    /// Applies the results of the requests that finished and sends the events, with
+    /// [`done_refreshing_leaderboard`] or [`done_refreshing_leaderboards`].
+    Receive,
+    /// Refreshes the leaderboard periodically, with [`auto_refresh_leaderboard`].
+    AutoRefresh,
+}
+
+/// Where the [`JornetPlugin`] gets the credentials of the leaderboard.
+enum Credentials {
+    Leaderboard {
+        id: Uuid,
+        key: Uuid,
+    },
+    /// Path of a [`JornetConfig`] asset.
+    #[cfg(feature = "config")]
+    Config(String),
+}
+
+/// Error returned by [`JornetPlugin::try_with_leaderboard`] when the ID or key of the
+/// leaderboard is not a valid `UUID`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidCredentials {
+    /// The ID of the leaderboard is invalid, for the given reason.
+    Id(String),
+    /// The key of the leaderboard is invalid, for the given reason.
+    Key(String),
+}
+
+impl fmt::Display for InvalidCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidCredentials::Id(error) => write!(f, "invalid leaderboard ID: {}", error),
+            InvalidCredentials::Key(error) => write!(f, "invalid leaderboard key: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for InvalidCredentials {}
+
+impl InvalidCredentials {
+    /// Parse the ID and key of a leaderboard.
+    pub(crate) fn parse(id: &str, key: &str) -> Result<(Uuid, Uuid), Self> {
+        Ok((
+            Uuid::parse_str(id).map_err(|error| InvalidCredentials::Id(error.to_string()))?,
+            Uuid::parse_str(key).map_err(|error| InvalidCredentials::Key(error.to_string()))?,
+        ))
+    }
+}
+
+/// Result of a request, received by [`done_refreshing_leaderboard`].
+enum Outcome {
+    PlayerCreated(Player),
+    PlayerConnected(Player),
+    ScoreSubmitted(jornet::SubmittedScore),
+    SubmitFailed(jornet::JornetError),
+    LeaderboardRefreshed(Vec<Score>),
+    RefreshFailed(jornet::JornetError),
+    Failed(jornet::JornetError),
 }
 
 /// Leaderboard resource, used to interact with Jornet leaderboard.
+///
+/// Requests are spawned as tasks on the [`AsyncComputeTaskPool`], which are polled by
+/// [`done_refreshing_leaderboard`] to apply their results to the resource. It also sends the
+/// events [`PlayerCreated`], [`ScoreSubmitted`], [`LeaderboardRefreshed`] and [`JornetError`].
+///
+/// With the `reflect` feature, the current player and the scores can be inspected through
+/// `Reflect`, but changing them doesn't send anything to the server.
+#[derive(Resource)]
+#[cfg_attr(feature = "reflect", derive(bevy::reflect::Reflect))]
+pub struct Leaderboard {
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    id: Uuid,
+    /// Host shown by the debug panel.
+    #[cfg(feature = "debug-egui")]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    host: Option<String>,
+    /// Client used by requests, built once so that they share its connections. Scores are sent
+    /// as [`Self::player`], which the client doesn't keep.
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    inner: Arc<jornet::Leaderboard>,
+    player: Option<Player>,
+    scores: Vec<Score>,
+    /// Requests in progress.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    tasks: Mutex<Vec<Task<Outcome>>>,
+    /// Results of the requests that finished since the last update, as tasks can't be polled
+    /// on wasm.
+    #[cfg(target_arch = "wasm32")]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    outcomes: Arc<Mutex<Vec<Outcome>>>,
+    /// Number of refreshes in progress.
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    refreshing: AtomicUsize,
+    /// Number of scores sent that the server didn't receive yet.
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    submitting: AtomicUsize,
+    /// Whether the last refresh failed.
+    refresh_failed: bool,
+    /// Where created players are saved.
+    #[cfg(feature = "persistence")]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    saved_player: String,
+}
+
+impl Leaderboard {
+    pub(crate) fn new(host: Option<String>, id: Uuid, key: Uuid) -> Self {
+        Self {
+            inner: Arc::new(jornet::Leaderboard::with_host_and_leaderboard(
+                host.clone(),
+                id,
+                key,
+            )),
+            id,
+            #[cfg(feature = "debug-egui")]
+            host,
+            player: None,
+            scores: vec![],
+            #[cfg(not(target_arch = "wasm32"))]
+            tasks: Default::default(),
+            #[cfg(target_arch = "wasm32")]
+            outcomes: Default::default(),
+            refreshing: AtomicUsize::new(0),
+            submitting: AtomicUsize::new(0),
+            refresh_failed: false,
+            #[cfg(feature = "persistence")]
+            saved_player: DEFAULT_SAVED_PLAYER.to_string(),
+        }
+    }
+
+    /// Save created players at `location`, or [`DEFAULT_SAVED_PLAYER`].
+    #[cfg(feature = "persistence")]
+    pub(crate) fn saving_player_at(self, location: Option<&str>) -> Self {
+        Self {
+            saved_player: location.unwrap_or(DEFAULT_SAVED_PLAYER).to_string(),
+            ..self
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn spawn(&self, request: impl Future<Output = Outcome> + Send + 'static) {
+        let task = AsyncComputeTaskPool::get().spawn(request);
+        self.tasks.lock().unwrap().push(task);
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn spawn(&self, request: impl Future<Output = Outcome> + 'static) {
+        // tasks spawned on wasm can't be polled, they push their result instead
+        let outcomes = self.outcomes.clone();
+        AsyncComputeTaskPool::get()
+            .spawn(async move {
+                let outcome = request.await;
+                outcomes.lock().unwrap().push(outcome);
+            })
+            .detach();
+    }
+
+    /// Create a player. If you don't specify a name, one will be generated randomly.
+    ///
+    /// The player is set once the server answers, and [`PlayerCreated`] then
+    /// [`PlayerConnected`] are sent.
+    pub fn create_player(&mut self, name: Option<&str>) {
+        let inner = self.inner.clone();
+        let name = name.map(str::to_string);
+        self.spawn(async move {
+            match inner.new_player(name.as_deref()).await {
+                Ok(player) => Outcome::PlayerCreated(player),
+                Err(error) => Outcome::Failed(error),
+            }
+        });
+    }
+
+    /// Connect as the player saved at `location`, or create a player named `name` and save it
+    /// there if there is none, so that the game keeps the same player across sessions. On
+    /// native, `location` is the path of a file, and on wasm a key of `localStorage`.
+    ///
+    /// [`PlayerConnected`] is sent once the player is ready, after [`PlayerCreated`] if it was
+    /// created.
+    pub fn connect_or_create(&mut self, location: &str, name: Option<&str>) {
+        let inner = self.inner.clone();
+        let location = location.to_string();
+        let name = name.map(str::to_string);
+        self.spawn(async move {
+            match Player::load_from(&location) {
+                Ok(Some(player)) => Outcome::PlayerConnected(player),
+                Ok(None) => match inner.new_player(name.as_deref()).await {
+                    Ok(player) => match player.save_to(&location) {
+                        Ok(()) => Outcome::PlayerCreated(player),
+                        Err(error) => Outcome::Failed(error),
+                    },
+                    Err(error) => Outcome::Failed(error),
+                },
+                Err(error) => Outcome::Failed(error),
+            }
+        });
+    }
+
+    /// Connect as the player saved at `location` with [`Player::save_to`], if there is one.
+    /// Returns whether a player was found, and [`PlayerConnected`] is then sent.
+    pub fn restore_player(&mut self, location: &str) -> Result<bool, jornet::JornetError> {
+        match Player::load_from(location)? {
+            Some(player) => {
+                self.spawn(async move { Outcome::PlayerConnected(player) });
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Connect as a returning player.
+    pub fn as_player(&mut self, player: Player) {
+        self.player = Some(player);
+    }
+
+    /// Get the current player, if one was created or connected.
+    pub fn get_player(&self) -> Option<&Player> {
+        self.player.as_ref()
+    }
+
+    /// Send a score to the leaderboard, as the current player.
+    ///
+    /// [`ScoreSubmitted`] is sent once the server received it.
+    pub fn send_score(&self, score: f32) {
+        let inner = self.inner.clone();
+        let player = self.player.clone();
+        self.submitting.fetch_add(1, Ordering::Relaxed);
+        self.spawn(async move {
+            let Some(player) = player else {
+                return Outcome::SubmitFailed(jornet::JornetError::NotConnectedAsPlayer);
+            };
+            match inner
+                .submit_as(&player, jornet::ScoreSubmission::new(score))
+                .await
+            {
+                Ok(submitted) => Outcome::ScoreSubmitted(submitted),
+                Err(error) => Outcome::SubmitFailed(error),
+            }
+        });
+    }
+
+    /// Refresh the leaderboard. The scores can be read with [`Self::get_leaderboard`] once
+    /// [`LeaderboardRefreshed`] is sent.
+    pub fn refresh_leaderboard(&self) {
+        let inner = self.inner.clone();
+        self.refreshing.fetch_add(1, Ordering::Relaxed);
+        self.spawn(async move {
+            match inner.get_leaderboard().await {
+                Ok(scores) => Outcome::LeaderboardRefreshed(scores),
+                Err(error) => Outcome::RefreshFailed(error),
+            }
+        });
+    }
+
+    /// Get the scores of the leaderboard, as received by the last refresh.
+    pub fn get_leaderboard(&self) -> Vec<Score> {
+        self.scores.clone()
+    }
+
+    #[cfg(feature = "ui")]
+    pub(crate) fn scores(&self) -> &[Score] {
+        &self.scores
+    }
+
+    /// ID of the leaderboard.
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Whether a refresh of the leaderboard is in progress.
+    pub fn is_refreshing(&self) -> bool {
+        self.refreshing.load(Ordering::Relaxed) > 0
+    }
+
+    /// Number of scores sent with [`Self::send_score`] that the server didn't receive yet.
+    pub fn pending_scores(&self) -> usize {
+        self.submitting.load(Ordering::Relaxed)
+    }
+
+    /// Whether the last refresh of the leaderboard failed, in which case the scores are the ones
+    /// of the refresh before.
+    pub fn refresh_failed(&self) -> bool {
+        self.refresh_failed
+    }
+
+    /// Poll the requests in progress, and take the results of those that finished.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn take_outcomes(&self) -> Vec<Outcome> {
+        let mut outcomes = vec![];
+        self.tasks.lock().unwrap().retain_mut(|task| {
+            match future::block_on(future::poll_once(task)) {
+                Some(outcome) => {
+                    outcomes.push(outcome);
+                    false
+                }
+                None => true,
+            }
+        });
+        outcomes
+    }
+
+    /// Take the results of the requests that finished.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn take_outcomes(&self) -> Vec<Outcome> {
+        std::mem::take(&mut *self.outcomes.lock().unwrap())
+    }
+
+    /// Apply the results of requests, and send their events. Returns the last player connected,
+    /// if any.
+    pub(crate) fn apply_outcomes(
+        &mut self,
+        outcomes: Vec<Outcome>,
+        events: &mut JornetEvents,
+    ) -> Option<Player> {
+        let mut connected = None;
+        for outcome in outcomes {
+            match outcome {
+                Outcome::PlayerCreated(player) => {
+                    #[cfg(feature = "persistence")]
+                    if let Err(error) = player.save_to(&self.saved_player) {
+                        events.errors.send(JornetError(error));
+                    }
+                    self.as_player(player.clone());
+                    events.player_created.send(PlayerCreated(player.clone()));
+                    events
+                        .player_connected
+                        .send(PlayerConnected(player.clone()));
+                    connected = Some(player);
+                }
+                Outcome::PlayerConnected(player) => {
+                    self.as_player(player.clone());
+                    events
+                        .player_connected
+                        .send(PlayerConnected(player.clone()));
+                    connected = Some(player);
+                }
+                Outcome::ScoreSubmitted(submitted) => {
+                    self.submitting.fetch_sub(1, Ordering::Relaxed);
+                    events.score_submitted.send(ScoreSubmitted {
+                        leaderboard: self.id,
+                        rank: submitted.rank,
+                    });
+                }
+                Outcome::SubmitFailed(error) => {
+                    self.submitting.fetch_sub(1, Ordering::Relaxed);
+                    events.errors.send(JornetError(error));
+                }
+                Outcome::LeaderboardRefreshed(scores) => {
+                    self.refreshing.fetch_sub(1, Ordering::Relaxed);
+                    self.refresh_failed = false;
+                    self.scores = scores;
+                    events.leaderboard_refreshed.send(LeaderboardRefreshed {
+                        leaderboard: self.id,
+                    });
+                }
+                Outcome::RefreshFailed(error) => {
+                    self.refreshing.fetch_sub(1, Ordering::Relaxed);
+                    self.refresh_failed = true;
+                    events.errors.send(JornetError(error));
+                }
+                Outcome::Failed(error) => events.errors.send(JornetError(error)),
+            }
+        }
+        connected
+    }
+}
+
+/// System polling the requests of the [`Leaderboard`] resource, to refresh it when new data is
+/// available and send events for the requests that finished.
+/// It is automatically added by the [`JornetPlugin`] in the set [`JornetSystems::Receive`].
+pub fn done_refreshing_leaderboard(mut leaderboard: ResMut<Leaderboard>, mut events: JornetEvents) {
+    // taken without marking the resource as changed, as nothing may have finished
+    let outcomes = leaderboard.take_outcomes();
+    if !outcomes.is_empty() {
+        leaderboard.apply_outcomes(outcomes, &mut events);
+    }
+}
+
+/// Player to connect on startup, from the options of the [`JornetPlugin`].
 #[derive(Resource)]
-pub struct Leaderboard(jornet::Leaderboard);
+struct StartupPlayer {
+    /// Where the player is saved, which is on the [`Leaderboard`] with the `persistence`
+    /// feature.
+    #[cfg(not(feature = "persistence"))]
+    location: Option<String>,
+    name: Option<String>,
+}
+
+#[cfg(not(feature = "persistence"))]
+fn connect_startup_player(startup: Res<StartupPlayer>, mut leaderboard: ResMut<Leaderboard>) {
+    match (&startup.location, &startup.name) {
+        (Some(location), name) => leaderboard.connect_or_create(location, name.as_deref()),
+        (None, Some(name)) => leaderboard.create_player(Some(name)),
+        (None, None) => (),
+    }
+}
 
-/// System to handle refreshing the [`Leaderboard`] resource when new data is available.
-/// It is automatically added by the [`JornetPlugin`](crate::JornetPlugin) in stage
-/// [`CoreStage::Update`](bevy::prelude::CoreStage).
-pub fn done_refreshing_leaderboard(mut leaderboard: ResMut<Leaderboard>) {
-    leaderboard.0.check_for_updates();
+#[cfg(feature = "persistence")]
+fn connect_startup_player(
+    startup: Res<StartupPlayer>,
+    mut leaderboard: ResMut<Leaderboard>,
+    mut events: JornetEvents,
+) {
+    let location = leaderboard.saved_player.clone();
+    match leaderboard.restore_player(&location) {
+        Ok(true) => (),
+        Ok(false) => {
+            if let Some(name) = &startup.name {
+                leaderboard.create_player(Some(name));
+            }
+        }
+        Err(error) => events.errors.send(JornetError(error)),
+    }
+}
+
+/// Register the types that can be inspected with the `reflect` feature.
+#[cfg(feature = "reflect")]
+pub(crate) fn register_types(app: &mut App) {
+    app.register_type::<Leaderboard>()
+        .register_type::<Score>()
+        .register_type::<Player>();
+}
+
+/// Add `system` to `schedule`. `in_schedule` can't be used with a boxed label, as it would be
+/// boxed again and not found.
+pub(crate) fn add_system_to<M>(
+    app: &mut App,
+    schedule: &dyn ScheduleLabel,
+    system: impl IntoSystemConfig<M>,
+) {
+    match app.world.resource_mut::<Schedules>().get_mut(schedule) {
+        Some(schedule) => {
+            schedule.add_system(system);
+        }
+        None => panic!("Schedule {:?} does not exist.", schedule),
+    }
 }
 
 impl Plugin for JornetPlugin {
     fn build(&self, app: &mut App) {
-        let leaderboard = Leaderboard(jornet::Leaderboard::with_host_and_leaderboard(
-            self.host.clone(),
-            self.leaderboard,
-            self.key,
-        ));
-        app.insert_resource(leaderboard)
-            .add_system(done_refreshing_leaderboard);
+        match &self.credentials {
+            Credentials::Leaderboard { id, key } => {
+                let leaderboard = Leaderboard::new(self.host.clone(), *id, *key);
+                #[cfg(feature = "persistence")]
+                let leaderboard = leaderboard.saving_player_at(self.saved_player.as_deref());
+                app.insert_resource(leaderboard);
+            }
+            #[cfg(feature = "config")]
+            Credentials::Config(path) => {
+                app.add_asset::<JornetConfig>()
+                    .init_asset_loader::<JornetConfigLoader>()
+                    .insert_resource(config::PendingConfig {
+                        path: path.clone(),
+                        handle: Default::default(),
+                        host: self.host.clone(),
+                        #[cfg(feature = "persistence")]
+                        saved_player: self.saved_player.clone(),
+                    })
+                    .add_startup_system(config::load_config);
+                add_system_to(
+                    app,
+                    &*self.schedule,
+                    config::init_from_config.run_if(resource_exists::<config::PendingConfig>()),
+                );
+            }
+        }
+        events::add_events(app);
+        #[cfg(feature = "reflect")]
+        register_types(app);
+        app.insert_resource(StartupPlayer {
+            #[cfg(not(feature = "persistence"))]
+            location: self.saved_player.clone(),
+            name: self.player_name.clone(),
+        });
+        // started from a system once the task pools are ready, and the leaderboard is known
+        add_system_to(
+            app,
+            &*self.schedule,
+            connect_startup_player.run_if(resource_added::<Leaderboard>()),
+        );
+        add_system_to(
+            app,
+            &*self.schedule,
+            done_refreshing_leaderboard
+                .in_set(JornetSystems::Receive)
+                .run_if(resource_exists::<Leaderboard>()),
+        );
+        if let Some(interval) = self.auto_refresh {
+            app.insert_resource(AutoRefresh::new(interval));
+            add_system_to(
+                app,
+                &*self.schedule,
+                auto_refresh_leaderboard
+                    .in_set(JornetSystems::AutoRefresh)
+                    .run_if(resource_exists::<Leaderboard>()),
+            );
+        }
+        app.add_state::<JornetState>()
+            .init_resource::<LocalPlayerRank>();
+        add_system_to(
+            app,
+            &*self.schedule,
+            update_jornet_state.after(JornetSystems::Receive),
+        );
+        add_system_to(
+            app,
+            &*self.schedule,
+            update_local_player_rank
+                .after(JornetSystems::Receive)
+                .run_if(resource_exists_and_changed::<Leaderboard>()),
+        );
+        #[cfg(feature = "debug-egui")]
+        debug::add_panel(app, &*self.schedule);
+        #[cfg(feature = "ui")]
+        add_system_to(
+            app,
+            &*self.schedule,
+            update_leaderboard_widgets
+                .after(JornetSystems::Receive)
+                .run_if(resource_exists::<Leaderboard>()),
+        );
     }
 }
@@ -0,0 +1,51 @@
+use bevy::prelude::{DetectChangesMut, Res, ResMut, Resource};
+
+use crate::{Leaderboard, Player, Score};
+
+/// Resource with the place of the current player in the scores of the [`Leaderboard`], kept up
+/// to date by the [`JornetPlugin`](crate::JornetPlugin) when the scores are refreshed or the
+/// player changes.
+///
+/// It only changes when its values do, so `Res<LocalPlayerRank>::is_changed` can be used to
+/// update what shows it.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Default)]
+pub struct LocalPlayerRank {
+    /// Rank of the best score of the current player, starting at 1, or `None` if there is no
+    /// player or it has no score in the leaderboard.
+    pub rank: Option<u32>,
+    /// Best score of the current player, according to the order of the leaderboard.
+    pub best_score: Option<f32>,
+    /// Number of scores received by the last refresh of the leaderboard.
+    pub total_entries: usize,
+}
+
+/// Whether `score` was sent by `player`.
+pub(crate) fn is_score_of(score: &Score, player: &Player) -> bool {
+    match score.player_id {
+        Some(id) => id == player.id,
+        // servers that don't expose the IDs of players
+        None => score.player == player.name,
+    }
+}
+
+/// System updating the [`LocalPlayerRank`] resource when the [`Leaderboard`] changed.
+/// It is automatically added by the [`JornetPlugin`](crate::JornetPlugin), after the set
+/// [`JornetSystems::Receive`](crate::JornetSystems::Receive).
+pub fn update_local_player_rank(
+    leaderboard: Res<Leaderboard>,
+    mut local_rank: ResMut<LocalPlayerRank>,
+) {
+    let order = leaderboard.inner.order();
+    let best = leaderboard.get_player().and_then(|player| {
+        leaderboard
+            .scores
+            .iter()
+            .filter(|score| is_score_of(score, player))
+            .min_by(|a, b| order.compare(a, b))
+    });
+    local_rank.set_if_neq(LocalPlayerRank {
+        rank: best.and_then(|score| score.rank),
+        best_score: best.map(|score| score.score),
+        total_entries: leaderboard.scores.len(),
+    });
+}
@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::{Res, ResMut, Resource},
+    time::{Time, Timer, TimerMode},
+};
+
+use crate::Leaderboard;
+
+/// Resource refreshing the [`Leaderboard`] periodically, added by
+/// [`JornetPlugin::with_auto_refresh`](crate::JornetPlugin::with_auto_refresh). It can be paused
+/// while the scores aren't shown, like during a game.
+#[derive(Resource, Debug)]
+pub struct AutoRefresh {
+    timer: Timer,
+    paused: bool,
+}
+
+impl AutoRefresh {
+    pub(crate) fn new(interval: Duration) -> Self {
+        let mut timer = Timer::new(interval, TimerMode::Repeating);
+        // the first refresh happens on startup
+        timer.set_elapsed(interval);
+        Self {
+            timer,
+            paused: false,
+        }
+    }
+
+    /// Stop refreshing the leaderboard until [`Self::resume`] is called.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Refresh the leaderboard again, starting immediately.
+    pub fn resume(&mut self) {
+        if self.paused {
+            self.paused = false;
+            let interval = self.timer.duration();
+            self.timer.set_elapsed(interval);
+        }
+    }
+
+    /// Whether refreshing is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Time between two refreshes.
+    pub fn interval(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    /// Change the time between two refreshes.
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.timer.set_duration(interval);
+    }
+}
+
+/// System refreshing the [`Leaderboard`] at the interval of [`AutoRefresh`], unless it's paused.
+/// It is added by [`JornetPlugin::with_auto_refresh`](crate::JornetPlugin::with_auto_refresh),
+/// in the set [`JornetSystems::AutoRefresh`](crate::JornetSystems::AutoRefresh).
+pub fn auto_refresh_leaderboard(
+    time: Res<Time>,
+    mut auto_refresh: ResMut<AutoRefresh>,
+    leaderboard: Res<Leaderboard>,
+) {
+    if auto_refresh.paused {
+        return;
+    }
+    if auto_refresh.timer.tick(time.delta()).just_finished() {
+        leaderboard.refresh_leaderboard();
+    }
+}
@@ -0,0 +1,76 @@
+use bevy::prelude::{EventReader, NextState, Res, ResMut, State, States};
+
+use crate::{JornetError, Leaderboard, LeaderboardRefreshed, PlayerConnected, ScoreSubmitted};
+
+/// State of the connection to the Jornet server, managed by the
+/// [`JornetPlugin`](crate::JornetPlugin), to run systems once a player is ready with
+/// `OnEnter(JornetState::Ready)` or [`in_state`](bevy::prelude::in_state).
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// use bevy_jornet::JornetState;
+///
+/// fn show_menu() {
+///     // ...
+/// }
+///
+/// App::new().add_system(show_menu.in_schedule(OnEnter(JornetState::Ready)));
+/// ```
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JornetState {
+    /// No player is connected yet, until one is created or restored.
+    #[default]
+    Connecting,
+    /// A player is connected, and the server answered the last request.
+    Ready,
+    /// The server couldn't be reached by the last request. The state changes again once a
+    /// request succeeds.
+    Offline,
+}
+
+/// Whether `error` means the server couldn't be reached, rather than it refusing the request.
+fn is_unreachable(error: &jornet::JornetError) -> bool {
+    match error {
+        jornet::JornetError::Network(_) | jornet::JornetError::Timeout => true,
+        jornet::JornetError::HttpStatus(status) => *status >= 500,
+        _ => false,
+    }
+}
+
+/// System changing the [`JornetState`] from the results of the requests.
+/// It is automatically added by the [`JornetPlugin`](crate::JornetPlugin), after the set
+/// [`JornetSystems::Receive`](crate::JornetSystems::Receive).
+pub fn update_jornet_state(
+    state: Res<State<JornetState>>,
+    mut next_state: ResMut<NextState<JornetState>>,
+    leaderboard: Option<Res<Leaderboard>>,
+    mut connected: EventReader<PlayerConnected>,
+    mut submitted: EventReader<ScoreSubmitted>,
+    mut refreshed: EventReader<LeaderboardRefreshed>,
+    mut errors: EventReader<JornetError>,
+) {
+    // all events are read, so that they aren't read again next frame
+    let connected = connected.iter().count() > 0;
+    let answered = submitted.iter().count() + refreshed.iter().count() > 0 || connected;
+    let unreachable = errors
+        .iter()
+        .filter(|JornetError(error)| is_unreachable(error))
+        .count()
+        > 0;
+    let has_player = leaderboard.is_some_and(|leaderboard| leaderboard.get_player().is_some());
+
+    let new_state = if connected {
+        JornetState::Ready
+    } else if unreachable {
+        JornetState::Offline
+    } else if answered && has_player {
+        JornetState::Ready
+    } else if answered {
+        JornetState::Connecting
+    } else {
+        return;
+    };
+    if state.0 != new_state {
+        next_state.set(new_state);
+    }
+}
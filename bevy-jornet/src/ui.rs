@@ -0,0 +1,191 @@
+use bevy::{
+    prelude::{
+        default, BuildChildren, Bundle, ChildBuilder, Color, Commands, Component,
+        DespawnRecursiveExt, DetectChanges, Entity, FlexDirection, Handle, JustifyContent,
+        NodeBundle, Query, Ref, Res, Size, Style, TextBundle, TextStyle, UiRect, Val,
+    },
+    text::Font,
+};
+
+use crate::{rank::is_score_of, Leaderboard, Score};
+
+/// Widget showing the scores of the [`Leaderboard`], one row per score with the row of the
+/// current player highlighted. It is rebuilt by [`update_leaderboard_widgets`] when the scores
+/// are refreshed, and shows a message while they are loading, if loading them failed, or if
+/// there are none.
+///
+/// The leaderboard is refreshed when a widget is spawned. It is usually spawned with
+/// [`LeaderboardWidgetBundle`].
+#[derive(Component, Debug, Clone)]
+pub struct LeaderboardWidget {
+    /// Maximum number of scores shown.
+    pub max_rows: usize,
+    /// Font of the texts.
+    pub font: Handle<Font>,
+    /// Size of the font of the texts.
+    pub font_size: f32,
+    /// Color of the texts.
+    pub text_color: Color,
+    /// Background colors of the rows, alternating.
+    pub row_colors: [Color; 2],
+    /// Background color of the row of the current player.
+    pub highlight_color: Color,
+    /// Text shown until the scores are received.
+    pub loading_text: String,
+    /// Text shown when the scores couldn't be received.
+    pub error_text: String,
+    /// Text shown when the leaderboard has no scores.
+    pub empty_text: String,
+}
+
+impl Default for LeaderboardWidget {
+    fn default() -> Self {
+        Self {
+            max_rows: 10,
+            font: default(),
+            font_size: 24.0,
+            text_color: Color::WHITE,
+            row_colors: [Color::rgba(1.0, 1.0, 1.0, 0.05), Color::NONE],
+            highlight_color: Color::rgba(1.0, 0.8, 0.2, 0.3),
+            loading_text: "Loading...".to_string(),
+            error_text: "Couldn't load the leaderboard".to_string(),
+            empty_text: "No scores yet".to_string(),
+        }
+    }
+}
+
+impl LeaderboardWidget {
+    fn text(&self, value: impl Into<String>) -> TextBundle {
+        TextBundle::from_section(
+            value,
+            TextStyle {
+                font: self.font.clone(),
+                font_size: self.font_size,
+                color: self.text_color,
+            },
+        )
+    }
+
+    fn spawn_row(&self, parent: &mut ChildBuilder, rank: usize, score: &Score, color: Color) {
+        parent
+            .spawn(NodeBundle {
+                style: Style {
+                    justify_content: JustifyContent::SpaceBetween,
+                    size: Size::width(Val::Percent(100.0)),
+                    padding: UiRect::horizontal(Val::Px(self.font_size / 2.0)),
+                    ..default()
+                },
+                background_color: color.into(),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn(self.text(format!("{}. {}", rank, score.player)));
+                row.spawn(self.text(score.score.to_string()));
+            });
+    }
+}
+
+/// Bundle of a [`LeaderboardWidget`], laying out its rows in a column.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// use bevy_jornet::{LeaderboardWidget, LeaderboardWidgetBundle};
+///
+/// fn spawn_scores(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn(LeaderboardWidgetBundle {
+///         widget: LeaderboardWidget {
+///             font: asset_server.load("FiraSans-Bold.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     });
+/// }
+/// ```
+#[derive(Bundle, Clone, Debug)]
+pub struct LeaderboardWidgetBundle {
+    /// The widget.
+    pub widget: LeaderboardWidget,
+    /// Node containing the rows.
+    pub node: NodeBundle,
+}
+
+impl Default for LeaderboardWidgetBundle {
+    fn default() -> Self {
+        Self {
+            widget: default(),
+            node: NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    size: Size::width(Val::Percent(100.0)),
+                    ..default()
+                },
+                ..default()
+            },
+        }
+    }
+}
+
+/// What a [`LeaderboardWidget`] is showing.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shown {
+    Loading,
+    Failed,
+    Empty,
+    Scores,
+}
+
+/// System rebuilding the [`LeaderboardWidget`]s when the [`Leaderboard`] changed, and
+/// refreshing it when a widget is spawned.
+/// It is automatically added by the [`JornetPlugin`](crate::JornetPlugin) with the `ui` feature,
+/// after the set [`JornetSystems::Receive`](crate::JornetSystems::Receive).
+pub fn update_leaderboard_widgets(
+    mut commands: Commands,
+    leaderboard: Res<Leaderboard>,
+    widgets: Query<(Entity, Ref<LeaderboardWidget>, Option<&Shown>)>,
+) {
+    if widgets.iter().any(|(_, widget, _)| widget.is_added()) && !leaderboard.is_refreshing() {
+        leaderboard.refresh_leaderboard();
+    }
+
+    let scores = leaderboard.scores();
+    let shown = if !scores.is_empty() {
+        Shown::Scores
+    } else if leaderboard.is_refreshing() {
+        Shown::Loading
+    } else if leaderboard.refresh_failed() {
+        Shown::Failed
+    } else {
+        Shown::Empty
+    };
+
+    for (entity, widget, previous) in &widgets {
+        if !leaderboard.is_changed() && !widget.is_changed() && previous == Some(&shown) {
+            continue;
+        }
+        let mut entity = commands.entity(entity);
+        entity.despawn_descendants();
+        entity.insert(shown).with_children(|parent| match shown {
+            Shown::Loading => {
+                parent.spawn(widget.text(widget.loading_text.as_str()));
+            }
+            Shown::Failed => {
+                parent.spawn(widget.text(widget.error_text.as_str()));
+            }
+            Shown::Empty => {
+                parent.spawn(widget.text(widget.empty_text.as_str()));
+            }
+            Shown::Scores => {
+                let player = leaderboard.get_player();
+                for (index, score) in scores.iter().take(widget.max_rows).enumerate() {
+                    let highlighted = player.is_some_and(|player| is_score_of(score, player));
+                    let color = if highlighted {
+                        widget.highlight_color
+                    } else {
+                        widget.row_colors[index % 2]
+                    };
+                    widget.spawn_row(parent, index + 1, score, color);
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,53 @@
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::Score;
+
+/// How the names of other players are shown in the scores received. See
+/// [`LeaderboardBuilder::player_names`](crate::LeaderboardBuilder::player_names).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlayerNames {
+    /// Names are shown as chosen by players.
+    #[default]
+    Shown,
+    /// Names are replaced by `***`.
+    Masked,
+    /// Names are replaced by a pseudonym like `Player 3FA2C1`, derived from the ID of the
+    /// player so that it's the same in every score of the player.
+    Pseudonyms,
+}
+
+/// Replaces the names of other players in scores, before they are returned by the
+/// [`Leaderboard`](crate::Leaderboard).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Anonymizer {
+    pub(crate) names: PlayerNames,
+    /// ID of the current player, whose name is kept.
+    pub(crate) player: Option<Uuid>,
+}
+
+impl Anonymizer {
+    pub(crate) fn apply(&self, scores: &mut [Score]) {
+        for score in scores {
+            self.apply_one(score);
+        }
+    }
+
+    pub(crate) fn apply_one(&self, score: &mut Score) {
+        if score.player_id.is_some() && score.player_id == self.player {
+            return;
+        }
+        score.player = match self.names {
+            PlayerNames::Shown => return,
+            PlayerNames::Masked => "***".to_string(),
+            PlayerNames::Pseudonyms => {
+                // scores sent before players had IDs only have a name to tell them apart
+                let hash = match score.player_id {
+                    Some(id) => Sha256::digest(id.as_bytes()),
+                    None => Sha256::digest(score.player.as_bytes()),
+                };
+                format!("Player {:02X}{:02X}{:02X}", hash[0], hash[1], hash[2])
+            }
+        };
+    }
+}
@@ -0,0 +1,424 @@
+//! A blocking client for Jornet, for applications without an async runtime.
+//!
+//! Each method blocks the current thread until the request completes, so it should not be
+//! called from an async context.
+
+use std::time::Duration;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    http::block_on, Aggregation, AutoRefresh, JornetError, LeaderboardInfo, LeaderboardStats,
+    LeaderboardView, Player, PlayerProfile, PlayerStats, Score, ScoreIter, ScoreOrder,
+    ScorePlacement, ScoreQuery, ScoreStream, ScoreSubmission, ScoreValue, Season, SubmittedScore,
+    TeamScore,
+};
+
+/// Used to interact with Jornet leaderboard, blocking on each request.
+pub struct Leaderboard {
+    inner: crate::Leaderboard,
+}
+
+impl From<crate::Leaderboard> for Leaderboard {
+    fn from(inner: crate::Leaderboard) -> Self {
+        Self { inner }
+    }
+}
+
+impl Leaderboard {
+    /// See [`crate::Leaderboard::with_host_and_leaderboard`].
+    pub fn with_host_and_leaderboard(host: Option<String>, id: Uuid, key: Uuid) -> Self {
+        crate::Leaderboard::with_host_and_leaderboard(host, id, key).into()
+    }
+
+    /// See [`crate::Leaderboard::ping`].
+    pub fn ping(&self) -> Result<Duration, JornetError> {
+        block_on(self.inner.ping())
+    }
+
+    /// See [`crate::Leaderboard::get_leaderboard_info`].
+    pub fn get_leaderboard_info(&self) -> Result<LeaderboardInfo, JornetError> {
+        block_on(self.inner.get_leaderboard_info())
+    }
+
+    /// See [`crate::Leaderboard::get_score_count`].
+    pub fn get_score_count(&self) -> Result<u64, JornetError> {
+        block_on(self.inner.get_score_count())
+    }
+
+    /// See [`crate::Leaderboard::get_player_count`].
+    pub fn get_player_count(&self) -> Result<u64, JornetError> {
+        block_on(self.inner.get_player_count())
+    }
+
+    /// See [`crate::Leaderboard::get_stats`].
+    pub fn get_stats(&self) -> Result<LeaderboardStats, JornetError> {
+        block_on(self.inner.get_stats())
+    }
+
+    /// See [`crate::Leaderboard::get_player_stats`].
+    pub fn get_player_stats(&self) -> Result<PlayerStats, JornetError> {
+        block_on(self.inner.get_player_stats())
+    }
+
+    /// See [`crate::Leaderboard::get_percentile`].
+    pub fn get_percentile(&self, value: ScoreValue) -> Result<ScorePlacement, JornetError> {
+        block_on(self.inner.get_percentile(value))
+    }
+
+    /// See [`crate::Leaderboard::order`].
+    pub fn order(&self) -> ScoreOrder {
+        self.inner.order()
+    }
+
+    /// See [`crate::Leaderboard::view`].
+    pub fn view(&self, scores: Vec<Score>) -> LeaderboardView {
+        self.inner.view(scores)
+    }
+
+    /// See [`crate::Leaderboard::sort_scores`].
+    pub fn sort_scores(&self, scores: &mut [Score]) {
+        self.inner.sort_scores(scores)
+    }
+
+    /// See [`crate::Leaderboard::get_player`].
+    pub fn get_player(&self) -> Option<&Player> {
+        self.inner.get_player()
+    }
+
+    /// See [`crate::Leaderboard::create_player`].
+    pub fn create_player(&mut self, name: Option<&str>) -> Result<&Player, JornetError> {
+        block_on(self.inner.create_player(name))
+    }
+
+    /// See [`crate::Leaderboard::as_player`].
+    pub fn as_player(&mut self, player: Player) {
+        self.inner.as_player(player)
+    }
+
+    /// See [`crate::Leaderboard::create_local_player`].
+    pub fn create_local_player(&mut self, name: Option<&str>) -> Result<&Player, JornetError> {
+        block_on(self.inner.create_local_player(name))
+    }
+
+    /// See [`crate::Leaderboard::add_local_player`].
+    pub fn add_local_player(&mut self, player: Player) {
+        self.inner.add_local_player(player)
+    }
+
+    /// See [`crate::Leaderboard::remove_local_player`].
+    pub fn remove_local_player(&mut self, id: Uuid) -> Option<Player> {
+        self.inner.remove_local_player(id)
+    }
+
+    /// See [`crate::Leaderboard::local_players`].
+    pub fn local_players(&self) -> impl Iterator<Item = &Player> {
+        self.inner.local_players()
+    }
+
+    /// See [`crate::Leaderboard::start_session`].
+    pub fn start_session(&self) -> Result<(), JornetError> {
+        block_on(self.inner.start_session())
+    }
+
+    /// See [`crate::Leaderboard::send_score`].
+    pub fn send_score(&self, score: f32) -> Result<SubmittedScore, JornetError> {
+        block_on(self.inner.send_score(score))
+    }
+
+    /// See [`crate::Leaderboard::send_duration`].
+    pub fn send_duration(&self, duration: Duration) -> Result<SubmittedScore, JornetError> {
+        block_on(self.inner.send_duration(duration))
+    }
+
+    /// See [`crate::Leaderboard::send_score_with_meta`].
+    pub fn send_score_with_meta(
+        &self,
+        score: f32,
+        meta: &str,
+    ) -> Result<SubmittedScore, JornetError> {
+        block_on(self.inner.send_score_with_meta(score, meta))
+    }
+
+    /// See [`crate::Leaderboard::send_score_with_meta_typed`].
+    pub fn send_score_with_meta_typed(
+        &self,
+        score: f32,
+        meta: impl Serialize,
+    ) -> Result<SubmittedScore, JornetError> {
+        block_on(self.inner.send_score_with_meta_typed(score, meta))
+    }
+
+    /// See [`crate::Leaderboard::send_score_with_dimensions`].
+    pub fn send_score_with_dimensions(
+        &self,
+        score: f32,
+        dimensions: &[(&str, f64)],
+    ) -> Result<SubmittedScore, JornetError> {
+        block_on(self.inner.send_score_with_dimensions(score, dimensions))
+    }
+
+    /// See [`crate::Leaderboard::send_score_i64`].
+    pub fn send_score_i64(&self, score: i64) -> Result<SubmittedScore, JornetError> {
+        block_on(self.inner.send_score_i64(score))
+    }
+
+    /// See [`crate::Leaderboard::send_score_i64_with_meta`].
+    pub fn send_score_i64_with_meta(
+        &self,
+        score: i64,
+        meta: &str,
+    ) -> Result<SubmittedScore, JornetError> {
+        block_on(self.inner.send_score_i64_with_meta(score, meta))
+    }
+
+    /// See [`crate::Leaderboard::submit`].
+    pub fn submit(&self, submission: ScoreSubmission) -> Result<SubmittedScore, JornetError> {
+        block_on(self.inner.submit(submission))
+    }
+
+    /// See [`crate::Leaderboard::send_score_for`].
+    pub fn send_score_for(&self, group: &str, score: f32) -> Result<SubmittedScore, JornetError> {
+        block_on(self.inner.send_score_for(group, score))
+    }
+
+    /// See [`crate::Leaderboard::send_score_if_best`].
+    pub fn send_score_if_best(&self, score: f32) -> Result<Option<SubmittedScore>, JornetError> {
+        block_on(self.inner.send_score_if_best(score))
+    }
+
+    /// See [`crate::Leaderboard::send_scores`].
+    pub fn send_scores(
+        &self,
+        scores: &[(f32, Option<&str>)],
+    ) -> Vec<Result<SubmittedScore, JornetError>> {
+        block_on(self.inner.send_scores(scores))
+    }
+
+    /// See [`crate::Leaderboard::send_queued_scores`].
+    pub fn send_queued_scores(&self) -> Result<usize, JornetError> {
+        block_on(self.inner.send_queued_scores())
+    }
+
+    /// See [`crate::Leaderboard::get_player_profile`].
+    pub fn get_player_profile(&self, id: Uuid) -> Result<PlayerProfile, JornetError> {
+        block_on(self.inner.get_player_profile(id))
+    }
+
+    /// See [`crate::Leaderboard::connect_or_create`].
+    pub fn connect_or_create(
+        &mut self,
+        location: &str,
+        name: Option<&str>,
+    ) -> Result<&Player, JornetError> {
+        block_on(self.inner.connect_or_create(location, name))
+    }
+
+    /// See [`crate::Leaderboard::link_player`].
+    pub fn link_player(&mut self, provider: &str, token: &str) -> Result<&Player, JornetError> {
+        block_on(self.inner.link_player(provider, token))
+    }
+
+    /// See [`crate::Leaderboard::connect_with_account`].
+    pub fn connect_with_account(
+        &mut self,
+        provider: &str,
+        token: &str,
+    ) -> Result<&Player, JornetError> {
+        block_on(self.inner.connect_with_account(provider, token))
+    }
+
+    /// See [`crate::Leaderboard::create_player_from_discord`].
+    pub fn create_player_from_discord(
+        &mut self,
+        access_token: &str,
+    ) -> Result<&Player, JornetError> {
+        block_on(self.inner.create_player_from_discord(access_token))
+    }
+
+    /// See [`crate::Leaderboard::create_player_from_itchio`].
+    pub fn create_player_from_itchio(&mut self, api_key: &str) -> Result<&Player, JornetError> {
+        block_on(self.inner.create_player_from_itchio(api_key))
+    }
+
+    /// See [`crate::Leaderboard::create_player_from_steam`].
+    #[cfg(feature = "steam")]
+    pub fn create_player_from_steam(&mut self, ticket: &[u8]) -> Result<&Player, JornetError> {
+        block_on(self.inner.create_player_from_steam(ticket))
+    }
+
+    /// See [`crate::Leaderboard::sync_steam_persona_name`].
+    #[cfg(feature = "steam")]
+    pub fn sync_steam_persona_name(&mut self, persona_name: &str) -> Result<&Player, JornetError> {
+        block_on(self.inner.sync_steam_persona_name(persona_name))
+    }
+
+    /// See [`crate::Leaderboard::create_recovery_code`].
+    pub fn create_recovery_code(&self) -> Result<String, JornetError> {
+        block_on(self.inner.create_recovery_code())
+    }
+
+    /// See [`crate::Leaderboard::recover_player`].
+    pub fn recover_player(&mut self, code: &str) -> Result<&Player, JornetError> {
+        block_on(self.inner.recover_player(code))
+    }
+
+    /// See [`crate::Leaderboard::delete_player`].
+    pub fn delete_player(&mut self) -> Result<(), JornetError> {
+        block_on(self.inner.delete_player())
+    }
+
+    /// See [`crate::Leaderboard::check_name_available`].
+    pub fn check_name_available(&self, name: &str) -> Result<bool, JornetError> {
+        block_on(self.inner.check_name_available(name))
+    }
+
+    /// See [`crate::Leaderboard::set_player_region`].
+    pub fn set_player_region(&mut self, region: Option<&str>) -> Result<(), JornetError> {
+        self.inner.set_player_region(region)
+    }
+
+    /// See [`crate::Leaderboard::lookup_region`].
+    pub fn lookup_region(&self) -> Result<Option<String>, JornetError> {
+        block_on(self.inner.lookup_region())
+    }
+
+    /// See [`crate::Leaderboard::rename_player`].
+    pub fn rename_player(&mut self, new_name: &str) -> Result<&Player, JornetError> {
+        block_on(self.inner.rename_player(new_name))
+    }
+
+    /// See [`crate::Leaderboard::delete_score`].
+    pub fn delete_score(&self, score_id: Uuid) -> Result<(), JornetError> {
+        block_on(self.inner.delete_score(score_id))
+    }
+
+    /// See [`crate::Leaderboard::send_score_with_attachment`].
+    pub fn send_score_with_attachment(
+        &self,
+        score: f32,
+        attachment: &[u8],
+    ) -> Result<SubmittedScore, JornetError> {
+        block_on(self.inner.send_score_with_attachment(score, attachment))
+    }
+
+    /// See [`crate::Leaderboard::upload_attachment`].
+    pub fn upload_attachment(&self, score_id: Uuid, attachment: &[u8]) -> Result<(), JornetError> {
+        block_on(self.inner.upload_attachment(score_id, attachment))
+    }
+
+    /// See [`crate::Leaderboard::download_attachment`].
+    pub fn download_attachment(&self, score: &Score) -> Result<Option<Vec<u8>>, JornetError> {
+        block_on(self.inner.download_attachment(score))
+    }
+
+    /// See [`crate::Leaderboard::get_leaderboard_iter`].
+    pub fn get_leaderboard_iter(&self) -> Result<ScoreIter, JornetError> {
+        block_on(self.inner.get_leaderboard_iter())
+    }
+
+    /// See [`crate::Leaderboard::stream_scores`]. Scores can be read without blocking with
+    /// [`ScoreStream::try_next`].
+    pub fn stream_scores(&self) -> ScoreStream {
+        self.inner.stream_scores()
+    }
+
+    /// See [`crate::Leaderboard::get_top`].
+    pub fn get_top(&self, n: usize) -> Result<Vec<Score>, JornetError> {
+        block_on(self.inner.get_top(n))
+    }
+
+    /// See [`crate::Leaderboard::get_personal_best`].
+    pub fn get_personal_best(&self) -> Result<Option<Score>, JornetError> {
+        block_on(self.inner.get_personal_best())
+    }
+
+    /// See [`crate::Leaderboard::get_player_scores`].
+    pub fn get_player_scores(&self) -> Result<Vec<Score>, JornetError> {
+        block_on(self.inner.get_player_scores())
+    }
+
+    /// See [`crate::Leaderboard::get_scores_since`].
+    pub fn get_scores_since(&self, timestamp: u64) -> Result<Vec<Score>, JornetError> {
+        block_on(self.inner.get_scores_since(timestamp))
+    }
+
+    /// See [`crate::Leaderboard::merge_scores`].
+    pub fn merge_scores(&self, scores: &mut Vec<Score>, new_scores: Vec<Score>) {
+        self.inner.merge_scores(scores, new_scores)
+    }
+
+    /// See [`crate::Leaderboard::get_leaderboard_for_players`].
+    pub fn get_leaderboard_for_players(&self, ids: &[Uuid]) -> Result<Vec<Score>, JornetError> {
+        block_on(self.inner.get_leaderboard_for_players(ids))
+    }
+
+    /// See [`crate::Leaderboard::get_team_leaderboard`].
+    pub fn get_team_leaderboard(
+        &self,
+        aggregation: Aggregation,
+    ) -> Result<Vec<TeamScore>, JornetError> {
+        block_on(self.inner.get_team_leaderboard(aggregation))
+    }
+
+    /// See [`crate::Leaderboard::search_scores`].
+    pub fn search_scores(&self, prefix: &str) -> Result<Vec<Score>, JornetError> {
+        block_on(self.inner.search_scores(prefix))
+    }
+
+    /// See [`crate::Leaderboard::get_current_season`].
+    pub fn get_current_season(&self) -> Result<Option<Season>, JornetError> {
+        block_on(self.inner.get_current_season())
+    }
+
+    /// See [`crate::Leaderboard::get_seasons`].
+    pub fn get_seasons(&self) -> Result<Vec<Season>, JornetError> {
+        block_on(self.inner.get_seasons())
+    }
+
+    /// See [`crate::Leaderboard::send_score_in_season`].
+    pub fn send_score_in_season(
+        &self,
+        season: &Season,
+        score: f32,
+    ) -> Result<SubmittedScore, JornetError> {
+        block_on(self.inner.send_score_in_season(season, score))
+    }
+
+    /// See [`crate::Leaderboard::get_season_leaderboard`].
+    pub fn get_season_leaderboard(&self, season: &Season) -> Result<Vec<Score>, JornetError> {
+        block_on(self.inner.get_season_leaderboard(season))
+    }
+
+    /// See [`crate::Leaderboard::get_leaderboard_for`].
+    pub fn get_leaderboard_for(&self, group: &str) -> Result<Vec<Score>, JornetError> {
+        block_on(self.inner.get_leaderboard_for(group))
+    }
+
+    /// See [`crate::Leaderboard::query`].
+    pub fn query(&self, query: ScoreQuery) -> Result<Vec<Score>, JornetError> {
+        block_on(self.inner.query(query))
+    }
+
+    /// See [`crate::Leaderboard::get_leaderboard_into`].
+    pub fn get_leaderboard_into(&self, scores: &mut Vec<Score>) -> Result<(), JornetError> {
+        block_on(self.inner.get_leaderboard_into(scores))
+    }
+
+    /// See [`crate::Leaderboard::cached_leaderboard`].
+    pub fn cached_leaderboard(&self) -> Option<Vec<Score>> {
+        self.inner.cached_leaderboard()
+    }
+
+    /// See [`crate::Leaderboard::start_auto_refresh`]. The latest leaderboard can be read
+    /// without blocking with [`AutoRefresh::latest`] or [`AutoRefresh::take_changes`].
+    pub fn start_auto_refresh(&self, interval: Duration) -> AutoRefresh {
+        self.inner.start_auto_refresh(interval)
+    }
+
+    /// See [`crate::Leaderboard::get_leaderboard`].
+    pub fn get_leaderboard(&self) -> Result<Vec<Score>, JornetError> {
+        block_on(self.inner.get_leaderboard())
+    }
+}
@@ -0,0 +1,376 @@
+use std::{sync::Arc, time::Duration};
+
+use uuid::Uuid;
+
+use crate::{
+    credentials::CredentialProtection, events::EventListener, http::HttpConfig,
+    queue::OfflineQueue, snapshot::SnapshotCache, submission::Validator, JornetError, Leaderboard,
+    LeaderboardEvent, NameFilter, NameGenerator, PlayerNames, ScoreOrder, ScoreSubmission,
+};
+
+/// Strategy used to wait between retries of a failed request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    /// Retry immediately.
+    None,
+    /// Wait the same delay before each retry.
+    Constant(Duration),
+    /// Wait `initial` before the first retry, then double the delay for each following retry,
+    /// up to `max`. A random jitter of up to half the delay is applied so that clients don't
+    /// retry in lockstep.
+    Exponential {
+        /// Delay before the first retry.
+        initial: Duration,
+        /// Maximum delay between two retries.
+        max: Duration,
+    },
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Backoff::Exponential {
+            initial: Duration::from_millis(500),
+            max: Duration::from_secs(10),
+        }
+    }
+}
+
+impl Backoff {
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        match self {
+            Backoff::None => Duration::ZERO,
+            Backoff::Constant(delay) => *delay,
+            Backoff::Exponential { initial, max } => {
+                let delay = initial
+                    .saturating_mul(2u32.saturating_pow(attempt))
+                    .min(*max);
+                delay / 2 + (delay / 2).mul_f64(random_fraction())
+            }
+        }
+    }
+
+    /// Longest delay between two retries.
+    pub(crate) fn max_delay(&self) -> Duration {
+        match self {
+            Backoff::None => Duration::ZERO,
+            Backoff::Constant(delay) => *delay,
+            Backoff::Exponential { max, .. } => *max,
+        }
+    }
+}
+
+/// A random number between 0 and 1, from the random bits of a v4 UUID.
+pub(crate) fn random_fraction() -> f64 {
+    (Uuid::new_v4().as_u128() as u64 >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Builder for a [`Leaderboard`], to configure how requests are sent to the server.
+pub struct LeaderboardBuilder {
+    id: Uuid,
+    key: Uuid,
+    http: HttpConfig,
+    idempotency_keys: bool,
+    nonces: bool,
+    session_tokens: bool,
+    offline_queue: Option<String>,
+    snapshot_cache: Option<(String, Duration)>,
+    order: ScoreOrder,
+    player_names: PlayerNames,
+    name_generator: Option<NameGenerator>,
+    name_filter: Option<NameFilter>,
+    unique_names: bool,
+    credential_protection: CredentialProtection,
+    listener: Option<EventListener>,
+    validators: Vec<Validator>,
+}
+
+impl LeaderboardBuilder {
+    /// Start building a [`Leaderboard`] with its `id` and `key`.
+    pub fn new(id: Uuid, key: Uuid) -> Self {
+        Self {
+            id,
+            key,
+            http: HttpConfig::default(),
+            idempotency_keys: false,
+            nonces: false,
+            session_tokens: false,
+            offline_queue: None,
+            snapshot_cache: None,
+            order: ScoreOrder::default(),
+            player_names: PlayerNames::default(),
+            name_generator: None,
+            name_filter: None,
+            unique_names: false,
+            credential_protection: CredentialProtection::default(),
+            listener: None,
+            validators: vec![],
+        }
+    }
+
+    /// Start building a [`Leaderboard`] configured from environment variables:
+    /// - `JORNET_LEADERBOARD_ID` and `JORNET_LEADERBOARD_KEY` are required
+    /// - `JORNET_HOST` is optional
+    ///
+    /// Variables are read at runtime, falling back to their values when the crate was compiled.
+    /// This makes it possible to configure wasm builds, that don't have access to the
+    /// environment at runtime.
+    pub fn from_env() -> Result<Self, JornetError> {
+        fn var(name: &str, compiled: Option<&'static str>) -> Option<String> {
+            std::env::var(name)
+                .ok()
+                .or_else(|| compiled.map(|value| value.to_string()))
+        }
+        fn uuid(name: &str, compiled: Option<&'static str>) -> Result<Uuid, JornetError> {
+            let value = var(name, compiled)
+                .ok_or_else(|| JornetError::InvalidConfiguration(format!("{} is not set", name)))?;
+            Uuid::parse_str(value.trim()).map_err(|error| {
+                JornetError::InvalidConfiguration(format!("{} is invalid: {}", name, error))
+            })
+        }
+
+        let builder = Self::new(
+            uuid(
+                "JORNET_LEADERBOARD_ID",
+                option_env!("JORNET_LEADERBOARD_ID"),
+            )?,
+            uuid(
+                "JORNET_LEADERBOARD_KEY",
+                option_env!("JORNET_LEADERBOARD_KEY"),
+            )?,
+        );
+        Ok(match var("JORNET_HOST", option_env!("JORNET_HOST")) {
+            Some(host) => builder.host(&host),
+            None => builder,
+        })
+    }
+
+    /// Use another host than <https://jornet.vleue.com>.
+    pub fn host(mut self, host: &str) -> Self {
+        self.http.hosts[0] = host.to_string();
+        self
+    }
+
+    /// Add a host to fail over to when the previous ones can't be reached. Hosts are tried in
+    /// the order they were added, after the main one.
+    pub fn fallback_host(mut self, host: &str) -> Self {
+        self.http.hosts.push(host.to_string());
+        self
+    }
+
+    /// Set the maximum duration of a request. By default, requests don't time out.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http.timeout = Some(timeout);
+        self
+    }
+
+    /// Set how many times a request failing with a network error, a timeout or a server error is
+    /// retried. Only requests that can safely be sent again are retried: creating a player never
+    /// is, and scores are only retried when sent with [idempotency keys](Self::idempotency_keys)
+    /// or [nonces](Self::nonces). By default, requests are retried 3 times.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.http.max_retries = max_retries;
+        self
+    }
+
+    /// Set the strategy used to wait between retries. By default, the delay starts at 500ms and
+    /// grows exponentially up to 10s.
+    pub fn backoff(mut self, backoff: Backoff) -> Self {
+        self.http.backoff = backoff;
+        self
+    }
+
+    /// Retry requests rejected because of rate limiting, waiting for the delay requested by the
+    /// server before sending them again. They count towards [`Self::max_retries`].
+    ///
+    /// If the server requests a longer delay than the longest one of the [`Self::backoff`], the
+    /// request is not retried and fails with
+    /// [`JornetError::RateLimited`](crate::JornetError::RateLimited).
+    ///
+    /// When not enabled, those requests fail with
+    /// [`JornetError::RateLimited`](crate::JornetError::RateLimited).
+    pub fn retry_rate_limited(mut self, retry: bool) -> Self {
+        self.http.retry_rate_limited = retry;
+        self
+    }
+
+    /// Add a header that will be sent with every request, for example to authenticate with a
+    /// gateway in front of a self-hosted server.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.http
+            .headers
+            .push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Identify your game to the server operator, for example `mygame/1.2.3`. It replaces the
+    /// default `User-Agent` header on native targets; browsers may not allow to change it.
+    pub fn user_agent(self, user_agent: &str) -> Self {
+        self.header("User-Agent", user_agent)
+    }
+
+    /// Send requests through the given [`ureq::Agent`] instead of the one created for this
+    /// leaderboard. The agent can be shared with the rest of your application, and its
+    /// connection pool, proxy and middlewares will be used.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn agent(mut self, agent: ureq::Agent) -> Self {
+        self.http.agent = agent;
+        self
+    }
+
+    /// Send each score with a random idempotency key, signed with the score. The key is the
+    /// same when a submission is retried, so that the server can store it only once even if a
+    /// previous attempt reached it.
+    ///
+    /// The server must support idempotency keys, otherwise it will reject the signature of the
+    /// scores.
+    pub fn idempotency_keys(mut self, enabled: bool) -> Self {
+        self.idempotency_keys = enabled;
+        self
+    }
+
+    /// Send each score with a random nonce, signed with the score, so that the server can
+    /// reject a captured score sent again. A score whose nonce was already used is reported
+    /// with [`SubmittedScore::replayed`](crate::SubmittedScore::replayed).
+    ///
+    /// The server must support nonces, otherwise it will reject the signature of the scores.
+    pub fn nonces(mut self, enabled: bool) -> Self {
+        self.nonces = enabled;
+        self
+    }
+
+    /// Sign scores with short-lived session tokens instead of the key of the player, so that
+    /// the key is only used once per session. Sessions are started with
+    /// [`Leaderboard::start_session`] or when the first score is sent, and renewed before they
+    /// expire.
+    ///
+    /// If the server doesn't support sessions, scores are signed with the key of the player.
+    /// Scores kept in the [offline queue](Self::offline_queue) are always signed with the key of
+    /// the player, as the session may have expired when they are sent.
+    pub fn session_tokens(mut self, enabled: bool) -> Self {
+        self.session_tokens = enabled;
+        self
+    }
+
+    /// Keep scores that couldn't be sent because the server was unreachable or rate limiting
+    /// requests, and send them again with their original timestamp before the next score. They
+    /// are persisted so that they are not lost if the game is closed. Scores rejected by the
+    /// server, even with a server error, are not kept.
+    ///
+    /// On native targets, `location` is the path of the file where scores are kept. On wasm, it
+    /// is the key used in `localStorage`.
+    pub fn offline_queue(mut self, location: &str) -> Self {
+        self.offline_queue = Some(location.to_string());
+        self
+    }
+
+    /// Save the leaderboard each time it's received with
+    /// [`Leaderboard::get_leaderboard`], so that it can be shown before the first request
+    /// completes the next time the game starts, with [`Leaderboard::cached_leaderboard`].
+    /// Snapshots older than `ttl` are ignored.
+    ///
+    /// On native targets, `location` is the path of the file where the snapshot is kept. On
+    /// wasm, it is the key used in `localStorage`.
+    pub fn snapshot_cache(mut self, location: &str, ttl: Duration) -> Self {
+        self.snapshot_cache = Some((location.to_string(), ttl));
+        self
+    }
+
+    /// Set which scores are better in this leaderboard, used when sorting scores or comparing
+    /// them. By default, higher scores are better.
+    pub fn order(mut self, order: ScoreOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Set how the names of other players are shown in the scores received, to avoid showing
+    /// names chosen by players in games for children. Names are replaced before scores are
+    /// returned by any method of the leaderboard, except for the scores of the current player.
+    /// By default, names are shown.
+    pub fn player_names(mut self, player_names: PlayerNames) -> Self {
+        self.player_names = player_names;
+        self
+    }
+
+    /// Generate the names of players created without one with `generator`, instead of letting
+    /// the server pick them. See [`Leaderboard::create_player`].
+    pub fn name_generator(mut self, generator: NameGenerator) -> Self {
+        self.name_generator = Some(generator);
+        self
+    }
+
+    /// Check the names of players with `filter` before creating or renaming them, failing with
+    /// [`JornetError::NameRejected`] without sending a request if a name is rejected.
+    pub fn name_filter(mut self, filter: NameFilter) -> Self {
+        self.name_filter = Some(filter);
+        self
+    }
+
+    /// Require players to have names that no other player has. Creating or renaming a player
+    /// then fails with [`JornetError::NameTaken`] if the name is taken, and the server is asked
+    /// to reserve the name of new players. Generated names that are taken are generated again.
+    /// See [`Leaderboard::check_name_available`].
+    pub fn unique_names(mut self, enabled: bool) -> Self {
+        self.unique_names = enabled;
+        self
+    }
+
+    /// Protect the player saved by [`Leaderboard::connect_or_create`] with `protection`. By
+    /// default, it's saved as plain JSON that anyone with access to it can copy.
+    pub fn credential_protection(mut self, protection: CredentialProtection) -> Self {
+        self.credential_protection = protection;
+        self
+    }
+
+    /// Call `listener` when a player is created, a score is sent, the leaderboard is received,
+    /// or when one of those operations is retried or fails. It can be used to show the progress
+    /// of operations without polling, or to forward events to a channel.
+    pub fn on_event(
+        mut self,
+        listener: impl Fn(&LeaderboardEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.listener = Some(EventListener(Arc::new(listener)));
+        self
+    }
+
+    /// Check scores before they are sent, to reject obviously impossible ones without a
+    /// request. `validator` returns why the score is invalid, and sending it then fails with
+    /// [`JornetError::InvalidScore`]. Validators are called in the order they were added.
+    ///
+    /// ```
+    /// # let (id, key) = (uuid::Uuid::nil(), uuid::Uuid::nil());
+    /// let leaderboard = jornet::Leaderboard::builder(id, key)
+    ///     .validator(|submission| match submission.value().as_f32() {
+    ///         score if score < 0.0 => Err("negative score".to_string()),
+    ///         _ => Ok(()),
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn validator(
+        mut self,
+        validator: impl Fn(&ScoreSubmission) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.validators.push(Arc::new(validator));
+        self
+    }
+
+    /// Build the [`Leaderboard`].
+    pub fn build(self) -> Leaderboard {
+        let mut leaderboard = Leaderboard::new(self.id, self.key, self.http);
+        leaderboard.idempotency_keys = self.idempotency_keys;
+        leaderboard.nonces = self.nonces;
+        leaderboard.session_tokens = self.session_tokens;
+        leaderboard.queue = self.offline_queue.map(OfflineQueue::new);
+        leaderboard.snapshot = self
+            .snapshot_cache
+            .map(|(location, ttl)| SnapshotCache::new(location, ttl));
+        leaderboard.order = self.order;
+        leaderboard.player_names = self.player_names;
+        leaderboard.name_generator = self.name_generator;
+        leaderboard.name_filter = self.name_filter;
+        leaderboard.unique_names = self.unique_names;
+        leaderboard.credential_protection = self.credential_protection;
+        leaderboard.listener = self.listener;
+        leaderboard.validators = self.validators;
+        leaderboard
+    }
+}
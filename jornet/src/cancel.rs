@@ -0,0 +1,101 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Poll, Waker},
+};
+
+use crate::JornetError;
+
+/// Token to cancel in-flight operations.
+///
+/// Wrap any operation with [`CancellationToken::run`], then call [`CancellationToken::cancel`]
+/// to stop waiting for it. The operation returns [`JornetError::Cancelled`] right away.
+///
+/// In the browser, the underlying request is aborted, as it is when the future of an operation
+/// is dropped. On native targets, requests can't be interrupted: a request already sent keeps
+/// running in the background and only its result is discarded, so a cancelled
+/// [`send_score`](crate::Leaderboard::send_score) can still reach the server.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    /// Waker of each operation running, by the ID of the operation.
+    wakers: Mutex<HashMap<u64, Waker>>,
+    next_id: AtomicU64,
+}
+
+/// Operation running with a token, whose waker is removed when it completes or is dropped.
+struct Running<'a> {
+    inner: &'a Inner,
+    id: u64,
+}
+
+impl Drop for Running<'_> {
+    fn drop(&mut self) {
+        self.inner.wakers.lock().unwrap().remove(&self.id);
+    }
+}
+
+impl CancellationToken {
+    /// Create a new token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel all operations running with this token, or that will be started with it.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        for (_, waker) in self.inner.wakers.lock().unwrap().drain() {
+            waker.wake();
+        }
+    }
+
+    /// Check if this token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Run an operation until it completes, or until this token is cancelled.
+    ///
+    /// ```no_run
+    /// # async fn example(leaderboard: jornet::Leaderboard) {
+    /// let token = jornet::CancellationToken::new();
+    /// let scores = token.run(leaderboard.get_leaderboard()).await;
+    /// # }
+    /// ```
+    pub async fn run<T>(
+        &self,
+        operation: impl Future<Output = Result<T, JornetError>>,
+    ) -> Result<T, JornetError> {
+        let mut operation = Box::pin(operation);
+        let running = Running {
+            inner: &self.inner,
+            id: self.inner.next_id.fetch_add(1, Ordering::Relaxed),
+        };
+        std::future::poll_fn(|cx| {
+            if self.is_cancelled() {
+                return Poll::Ready(Err(JornetError::Cancelled));
+            }
+            {
+                let mut wakers = self.inner.wakers.lock().unwrap();
+                match wakers.get_mut(&running.id) {
+                    Some(waker) if waker.will_wake(cx.waker()) => {}
+                    Some(waker) => waker.clone_from(cx.waker()),
+                    None => {
+                        wakers.insert(running.id, cx.waker().clone());
+                    }
+                }
+            }
+            operation.as_mut().poll(cx)
+        })
+        .await
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::{
+    http, http::HttpConfig, join, JornetError, Leaderboard, Player, PlayerInput, Score, ScoreOrder,
+};
+
+/// Maximum number of leaderboards fetched at the same time by
+/// [`JornetClient::get_leaderboards`], when the server can't send them in one request.
+const MAX_CONCURRENT_FETCHES: usize = 4;
+
+/// A client to a Jornet server, holding several leaderboards played by the same player.
+///
+/// The player is kept by the client, and used by every leaderboard added to it. It is changed
+/// through the client, so that the leaderboards always share the same player.
+///
+/// ```no_run
+/// # async fn run(easy: (uuid::Uuid, uuid::Uuid), hard: (uuid::Uuid, uuid::Uuid)) -> Result<(), jornet::JornetError> {
+/// let mut client = jornet::JornetClient::new(None);
+/// client.add_leaderboard(easy.0, easy.1);
+/// client.add_leaderboard(hard.0, hard.1);
+/// client.create_player(Some("player")).await?;
+///
+/// client.leaderboard(hard.0).unwrap().send_score(42.0).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct JornetClient {
+    http: HttpConfig,
+    player: Option<Player>,
+    leaderboards: Vec<Leaderboard>,
+}
+
+impl JornetClient {
+    /// Create a client to `host`, or to <https://jornet.vleue.com> if not specified.
+    pub fn new(host: Option<String>) -> Self {
+        let mut http = HttpConfig::default();
+        if let Some(host) = host {
+            http.hosts = vec![host];
+        }
+        Self {
+            http,
+            player: None,
+            leaderboards: vec![],
+        }
+    }
+
+    /// Add a header that will be sent with every following request, by this client and the
+    /// leaderboards added to it after.
+    pub fn add_header(&mut self, name: &str, value: &str) {
+        self.http
+            .headers
+            .push((name.to_string(), value.to_string()));
+    }
+
+    /// Add a leaderboard with its `id` and `key`, sending requests to the host of the client.
+    /// If a leaderboard with the same `id` was already added, it's replaced.
+    pub fn add_leaderboard(&mut self, id: Uuid, key: Uuid) -> &Leaderboard {
+        self.insert_leaderboard(Leaderboard::new(id, key, self.http.clone()))
+    }
+
+    /// Add a leaderboard configured with a [`LeaderboardBuilder`](crate::LeaderboardBuilder).
+    /// It keeps its configuration, but plays as the player of the client. If a leaderboard with
+    /// the same `id` was already added, it's replaced.
+    pub fn insert_leaderboard(&mut self, mut leaderboard: Leaderboard) -> &Leaderboard {
+        leaderboard.player = self.player.clone();
+        leaderboard.player_location = None;
+        self.leaderboards
+            .retain(|existing| existing.id != leaderboard.id);
+        self.leaderboards.push(leaderboard);
+        // just pushed - can't fail
+        self.leaderboards.last().unwrap()
+    }
+
+    /// Remove a leaderboard from the client.
+    pub fn remove_leaderboard(&mut self, id: Uuid) -> Option<Leaderboard> {
+        let index = self
+            .leaderboards
+            .iter()
+            .position(|leaderboard| leaderboard.id == id)?;
+        Some(self.leaderboards.remove(index))
+    }
+
+    /// Get a leaderboard added to the client.
+    pub fn leaderboard(&self, id: Uuid) -> Option<&Leaderboard> {
+        self.leaderboards
+            .iter()
+            .find(|leaderboard| leaderboard.id == id)
+    }
+
+    /// Iterate over the leaderboards added to the client, in the order they were added.
+    pub fn leaderboards(&self) -> impl Iterator<Item = &Leaderboard> {
+        self.leaderboards.iter()
+    }
+
+    /// Get the current player. See [`Leaderboard::get_player`].
+    pub fn get_player(&self) -> Option<&Player> {
+        self.player.as_ref()
+    }
+
+    /// Create a player, used by every leaderboard of the client. If you don't specify a name,
+    /// one will be generated randomly. The player is created with the configuration of the first
+    /// leaderboard added, like its [name generator](crate::LeaderboardBuilder::name_generator)
+    /// and [filter](crate::LeaderboardBuilder::name_filter).
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), err))]
+    pub async fn create_player(&mut self, name: Option<&str>) -> Result<&Player, JornetError> {
+        let player = match self.leaderboards.first_mut() {
+            Some(leaderboard) => leaderboard.create_player(name).await?.clone(),
+            None => {
+                let player = PlayerInput {
+                    name: name.map(|n| n.to_string()),
+                    unique: false,
+                };
+                http::post_once(&self.http, "/api/v1/players", player).await?
+            }
+        };
+        self.as_player(player);
+        // just set - can't fail
+        Ok(self.player.as_ref().unwrap())
+    }
+
+    /// Connect as a returning player, on every leaderboard of the client.
+    pub fn as_player(&mut self, player: Player) {
+        self.set_player(Some(player));
+    }
+
+    /// Change the name of the current player, on every leaderboard of the client. The name is
+    /// checked with the configuration of the first leaderboard added. See
+    /// [`Leaderboard::rename_player`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), err))]
+    pub async fn rename_player(&mut self, new_name: &str) -> Result<&Player, JornetError> {
+        let mut without_leaderboard = self.without_leaderboard();
+        let leaderboard = self
+            .leaderboards
+            .first_mut()
+            .unwrap_or(&mut without_leaderboard);
+        let player = leaderboard.rename_player(new_name).await?.clone();
+        self.as_player(player);
+        // just set - can't fail
+        Ok(self.player.as_ref().unwrap())
+    }
+
+    /// Delete the current player and all their scores from the server, and forget it on every
+    /// leaderboard of the client. See [`Leaderboard::delete_player`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), err))]
+    pub async fn delete_player(&mut self) -> Result<(), JornetError> {
+        let mut without_leaderboard = self.without_leaderboard();
+        let leaderboard = self
+            .leaderboards
+            .first_mut()
+            .unwrap_or(&mut without_leaderboard);
+        leaderboard.delete_player().await?;
+        if let Some(player) = self.player.take() {
+            for leaderboard in self.leaderboards.iter().skip(1) {
+                if let Some(queue) = &leaderboard.queue {
+                    queue.remove_player(player.id)?;
+                }
+            }
+        }
+        self.set_player(None);
+        Ok(())
+    }
+
+    /// Set the player of the client and of all its leaderboards, which are only lent immutably
+    /// so that they can't change it on their own.
+    fn set_player(&mut self, player: Option<Player>) {
+        for leaderboard in &mut self.leaderboards {
+            leaderboard.player = player.clone();
+            leaderboard.player_location = None;
+        }
+        self.player = player;
+    }
+
+    /// Leaderboard to change the player with when none was added to the client.
+    fn without_leaderboard(&self) -> Leaderboard {
+        let mut leaderboard = Leaderboard::new(Uuid::nil(), Uuid::nil(), self.http.clone());
+        leaderboard.player = self.player.clone();
+        leaderboard
+    }
+
+    /// Get the scores of several leaderboards in a single request, ranked according to the
+    /// [order](crate::LeaderboardBuilder::order) of the leaderboards added to the client.
+    /// Leaderboards don't need to be added to the client to be fetched, but their scores are
+    /// then ranked with the default order and show the names of players.
+    ///
+    /// If the server doesn't support fetching several leaderboards at once, they are fetched
+    /// concurrently.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), err))]
+    pub async fn get_leaderboards(
+        &self,
+        ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<Score>>, JornetError> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let leaderboards: Vec<String> = ids.iter().map(Uuid::to_string).collect();
+        let path = format!(
+            "/api/v1/scores{}",
+            http::query_string(&[("leaderboards", leaderboards.join(","))])
+        );
+        let mut boards: HashMap<Uuid, Vec<Score>> = match http::get(&self.http, &path).await {
+            Err(JornetError::HttpStatus(404)) => {
+                let fetches = ids
+                    .iter()
+                    .map(|id| async move {
+                        let path = format!("/api/v1/scores/{}", id);
+                        http::get(&self.http, &path)
+                            .await
+                            .map(|scores| (*id, scores))
+                    })
+                    .collect();
+                join::join_limited(fetches, MAX_CONCURRENT_FETCHES)
+                    .await
+                    .into_iter()
+                    .collect::<Result<_, _>>()?
+            }
+            result => result?,
+        };
+        for (id, scores) in &mut boards {
+            match self.leaderboard(*id) {
+                Some(leaderboard) => {
+                    leaderboard.order().rank(scores);
+                    leaderboard.anonymizer().apply(scores);
+                }
+                None => ScoreOrder::default().rank(scores),
+            }
+        }
+        Ok(boards)
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    task::{Poll, Waker},
+};
+
+use crate::JornetError;
+
+/// Shares the result of a request between the callers sending it at the same time, so that
+/// it's sent only once.
+pub(crate) struct Coalescer<T> {
+    in_flight: Mutex<HashMap<String, Arc<Mutex<Flight<T>>>>>,
+}
+
+/// A request being sent, and the callers waiting for its result.
+struct Flight<T> {
+    result: Option<Result<T, JornetError>>,
+    /// Whether the caller sending the request stopped before it completed.
+    abandoned: bool,
+    wakers: Vec<Waker>,
+}
+
+impl<T> Default for Coalescer<T> {
+    fn default() -> Self {
+        Self {
+            in_flight: Default::default(),
+        }
+    }
+}
+
+impl<T: Clone> Coalescer<T> {
+    /// Run the request built by `request`, unless the same request identified by `key` is
+    /// already being sent, in which case wait for its result instead.
+    pub(crate) async fn run<F, Fut>(&self, key: &str, request: F) -> Result<T, JornetError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, JornetError>>,
+    {
+        let mut request = Some(request);
+        loop {
+            let (flight, leader) = {
+                let mut in_flight = self.in_flight.lock().unwrap();
+                match in_flight.get(key) {
+                    Some(flight) => (flight.clone(), false),
+                    None => {
+                        let flight = Arc::new(Mutex::new(Flight {
+                            result: None,
+                            abandoned: false,
+                            wakers: vec![],
+                        }));
+                        in_flight.insert(key.to_string(), flight.clone());
+                        (flight, true)
+                    }
+                }
+            };
+            if leader {
+                let guard = FlightGuard {
+                    coalescer: self,
+                    key,
+                    flight: &flight,
+                };
+                // the request is only taken when leading, which ends the loop - can't fail
+                let result = (request.take().unwrap())().await;
+                guard.complete(result.clone());
+                return result;
+            }
+            let result = std::future::poll_fn(|cx| {
+                let mut flight = flight.lock().unwrap();
+                if let Some(result) = &flight.result {
+                    Poll::Ready(Some(result.clone()))
+                } else if flight.abandoned {
+                    Poll::Ready(None)
+                } else {
+                    flight.wakers.push(cx.waker().clone());
+                    Poll::Pending
+                }
+            })
+            .await;
+            if let Some(result) = result {
+                return result;
+            }
+        }
+    }
+}
+
+/// Completes a flight when its request completes, or abandons it if its future is dropped
+/// before, so that waiting callers send the request themselves.
+struct FlightGuard<'a, T> {
+    coalescer: &'a Coalescer<T>,
+    key: &'a str,
+    flight: &'a Arc<Mutex<Flight<T>>>,
+}
+
+impl<T> FlightGuard<'_, T> {
+    fn complete(self, result: Result<T, JornetError>) {
+        self.flight.lock().unwrap().result = Some(result);
+    }
+}
+
+impl<T> Drop for FlightGuard<'_, T> {
+    fn drop(&mut self) {
+        self.coalescer.in_flight.lock().unwrap().remove(self.key);
+        let mut flight = self.flight.lock().unwrap();
+        if flight.result.is_none() {
+            flight.abandoned = true;
+        }
+        for waker in flight.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+
+use crate::{storage, JornetError};
+
+/// How saved players are protected, as their key is a secret. See
+/// [`Player::save_protected`](crate::Player::save_protected) and
+/// [`LeaderboardBuilder::credential_protection`](crate::LeaderboardBuilder::credential_protection).
+#[derive(Clone, Default)]
+pub enum CredentialProtection {
+    /// Players are saved as plain JSON.
+    #[default]
+    None,
+    /// Players are encrypted with a key derived from a passphrase.
+    #[cfg(feature = "encryption")]
+    Passphrase(String),
+    /// Players are saved in the keychain of the platform: the Keychain on macOS and iOS, the
+    /// Credential Manager on Windows, and the Secret Service on Linux. The location is used as
+    /// the name of the credential, and no file is written.
+    #[cfg(all(feature = "keychain", not(target_arch = "wasm32")))]
+    Keychain,
+}
+
+impl fmt::Debug for CredentialProtection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialProtection::None => f.write_str("None"),
+            // the passphrase is a secret
+            #[cfg(feature = "encryption")]
+            CredentialProtection::Passphrase(_) => f.write_str("Passphrase(..)"),
+            #[cfg(all(feature = "keychain", not(target_arch = "wasm32")))]
+            CredentialProtection::Keychain => f.write_str("Keychain"),
+        }
+    }
+}
+
+impl CredentialProtection {
+    pub(crate) fn write(&self, location: &str, data: &str) -> Result<(), JornetError> {
+        match self {
+            CredentialProtection::None => storage::write(location, data),
+            #[cfg(feature = "encryption")]
+            CredentialProtection::Passphrase(passphrase) => {
+                storage::write(location, &encryption::encrypt(passphrase, data))
+            }
+            #[cfg(all(feature = "keychain", not(target_arch = "wasm32")))]
+            CredentialProtection::Keychain => keychain::entry(location)?
+                .set_password(data)
+                .map_err(keychain::error),
+        }
+    }
+
+    /// Read the saved data, if there is any.
+    pub(crate) fn read(&self, location: &str) -> Result<Option<String>, JornetError> {
+        match self {
+            CredentialProtection::None => Ok(storage::read(location)),
+            #[cfg(feature = "encryption")]
+            CredentialProtection::Passphrase(passphrase) => storage::read(location)
+                .map(|data| encryption::decrypt(passphrase, &data))
+                .transpose(),
+            #[cfg(all(feature = "keychain", not(target_arch = "wasm32")))]
+            CredentialProtection::Keychain => match keychain::entry(location)?.get_password() {
+                Ok(data) => Ok(Some(data)),
+                Err(keyring::Error::NoEntry) => Ok(None),
+                Err(error) => Err(keychain::error(error)),
+            },
+        }
+    }
+
+    pub(crate) fn remove(&self, location: &str) -> Result<(), JornetError> {
+        match self {
+            #[cfg(all(feature = "keychain", not(target_arch = "wasm32")))]
+            CredentialProtection::Keychain => {
+                match keychain::entry(location)?.delete_credential() {
+                    Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+                    Err(error) => Err(keychain::error(error)),
+                }
+            }
+            _ => storage::remove(location),
+        }
+    }
+}
+
+#[cfg(feature = "encryption")]
+mod encryption {
+    use chacha20poly1305::{aead::Aead, ChaCha20Poly1305, Key, KeyInit, Nonce};
+    use serde::{Deserialize, Serialize};
+    use sha2::Sha256;
+    use uuid::Uuid;
+
+    use crate::JornetError;
+
+    /// Iterations of PBKDF2 to derive the key from the passphrase.
+    const ROUNDS: u32 = 100_000;
+
+    #[derive(Serialize, Deserialize)]
+    struct Encrypted {
+        salt: String,
+        nonce: String,
+        data: String,
+    }
+
+    fn key(passphrase: &str, salt: &[u8]) -> Key {
+        let mut key = Key::default();
+        pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, ROUNDS, &mut key);
+        key
+    }
+
+    /// Random bytes for a nonce, from the random bits of a v4 UUID, skipping its version and
+    /// variant.
+    fn random_nonce() -> Nonce {
+        let bytes = Uuid::new_v4().into_bytes();
+        let mut nonce = Nonce::default();
+        nonce[..6].copy_from_slice(&bytes[..6]);
+        nonce[6..].copy_from_slice(&bytes[10..]);
+        nonce
+    }
+
+    pub(super) fn encrypt(passphrase: &str, data: &str) -> String {
+        let salt = Uuid::new_v4().into_bytes();
+        let nonce = random_nonce();
+        // encrypting in memory - can't fail
+        let encrypted = ChaCha20Poly1305::new(&key(passphrase, &salt))
+            .encrypt(&nonce, data.as_bytes())
+            .unwrap();
+        let encrypted = Encrypted {
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            data: hex::encode(encrypted),
+        };
+        // serializing hex strings - can't fail
+        serde_json::to_string(&encrypted).unwrap()
+    }
+
+    pub(super) fn decrypt(passphrase: &str, data: &str) -> Result<String, JornetError> {
+        let invalid = || JornetError::Storage("invalid encrypted player".to_string());
+        let encrypted: Encrypted = serde_json::from_str(data).map_err(|_| invalid())?;
+        let salt = hex::decode(encrypted.salt).map_err(|_| invalid())?;
+        let nonce = hex::decode(encrypted.nonce).map_err(|_| invalid())?;
+        let data = hex::decode(encrypted.data).map_err(|_| invalid())?;
+        if nonce.len() != Nonce::default().len() {
+            return Err(invalid());
+        }
+        let decrypted = ChaCha20Poly1305::new(&key(passphrase, &salt))
+            .decrypt(Nonce::from_slice(&nonce), data.as_slice())
+            .map_err(|_| {
+                JornetError::Storage("wrong passphrase, or corrupted player".to_string())
+            })?;
+        String::from_utf8(decrypted).map_err(|_| invalid())
+    }
+}
+
+#[cfg(all(feature = "keychain", not(target_arch = "wasm32")))]
+mod keychain {
+    use crate::JornetError;
+
+    /// Service under which players are saved in the keychain.
+    const SERVICE: &str = "jornet";
+
+    pub(super) fn entry(location: &str) -> Result<keyring::Entry, JornetError> {
+        keyring::Entry::new(SERVICE, location).map_err(error)
+    }
+
+    pub(super) fn error(error: keyring::Error) -> JornetError {
+        JornetError::Storage(error.to_string())
+    }
+}
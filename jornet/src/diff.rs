@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use crate::Score;
+
+/// Changes between two fetches of a leaderboard, to highlight new scores or players moving.
+///
+/// Scores are expected to be ranked, like those from
+/// [`Leaderboard::get_leaderboard`](crate::Leaderboard::get_leaderboard). Scores without a
+/// rank are ranked by their position.
+#[derive(Debug, Clone, Default)]
+pub struct LeaderboardDiff {
+    /// Scores that were not in the previous fetch.
+    pub inserted: Vec<Score>,
+    /// Scores that are no longer in the leaderboard.
+    pub removed: Vec<Score>,
+    /// Scores present in both fetches, whose rank changed.
+    pub rank_changes: Vec<RankChange>,
+    previous_best: HashMap<String, u32>,
+    current_best: HashMap<String, u32>,
+}
+
+/// A score whose rank changed between two fetches.
+#[derive(Debug, Clone)]
+pub struct RankChange {
+    /// The score, as in the current fetch.
+    pub score: Score,
+    /// Its rank in the previous fetch.
+    pub previous_rank: u32,
+    /// Its rank in the current fetch.
+    pub rank: u32,
+}
+
+impl LeaderboardDiff {
+    /// Compare the `previous` and `current` fetches of a leaderboard.
+    pub fn new(previous: &[Score], current: &[Score]) -> Self {
+        let mut diff = Self {
+            previous_best: best_ranks(previous),
+            current_best: best_ranks(current),
+            ..Default::default()
+        };
+        for (index, score) in current.iter().enumerate() {
+            match previous
+                .iter()
+                .enumerate()
+                .find(|(_, previous)| previous.is_same(score))
+            {
+                Some((previous_index, previous)) => {
+                    let previous_rank = rank(previous, previous_index);
+                    let rank = rank(score, index);
+                    if previous_rank != rank {
+                        diff.rank_changes.push(RankChange {
+                            score: score.clone(),
+                            previous_rank,
+                            rank,
+                        });
+                    }
+                }
+                None => diff.inserted.push(score.clone()),
+            }
+        }
+        diff.removed = previous
+            .iter()
+            .filter(|previous| !current.iter().any(|score| score.is_same(previous)))
+            .cloned()
+            .collect();
+        diff
+    }
+
+    /// Whether the leaderboard didn't change between the two fetches.
+    pub fn is_empty(&self) -> bool {
+        self.inserted.is_empty() && self.removed.is_empty() && self.rank_changes.is_empty()
+    }
+
+    /// Whether a score was not in the previous fetch, to highlight it as new.
+    pub fn is_new(&self, score: &Score) -> bool {
+        self.inserted.iter().any(|inserted| inserted.is_same(score))
+    }
+
+    /// Rank of the best score of a player in the previous and current fetches. Players are
+    /// identified by their name.
+    pub fn player_ranks(&self, player: &str) -> (Option<u32>, Option<u32>) {
+        (
+            self.previous_best.get(player).copied(),
+            self.current_best.get(player).copied(),
+        )
+    }
+
+    /// Whether the best rank of a player changed, for example the current player after sending
+    /// a score.
+    pub fn player_moved(&self, player: &str) -> bool {
+        let (previous, current) = self.player_ranks(player);
+        previous != current
+    }
+}
+
+fn rank(score: &Score, index: usize) -> u32 {
+    score.rank.unwrap_or(index as u32 + 1)
+}
+
+/// Best rank of each player.
+fn best_ranks(scores: &[Score]) -> HashMap<String, u32> {
+    let mut best = HashMap::new();
+    for (index, score) in scores.iter().enumerate() {
+        let rank = rank(score, index);
+        best.entry(score.player.clone())
+            .and_modify(|best: &mut u32| *best = (*best).min(rank))
+            .or_insert(rank);
+    }
+    best
+}
@@ -0,0 +1,80 @@
+use std::{fmt, time::Duration};
+
+/// Errors that can happen when interacting with a Jornet server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JornetError {
+    /// The server could not be reached.
+    Network(String),
+    /// The request took longer than the configured timeout.
+    Timeout,
+    /// The server answered with an error status code.
+    HttpStatus(u16),
+    /// The server is rate limiting requests. If it specified when to retry, `retry_after` is how
+    /// long to wait before sending another request.
+    RateLimited {
+        /// Delay requested by the server through the `Retry-After` header.
+        retry_after: Option<Duration>,
+    },
+    /// The response from the server could not be deserialized.
+    Deserialization(String),
+    /// A value could not be serialized to be sent to the server.
+    Serialization(String),
+    /// A player must be created or connected before this operation.
+    NotConnectedAsPlayer,
+    /// The configuration of the leaderboard is invalid.
+    InvalidConfiguration(String),
+    /// A score was rejected by a validator before being sent. See
+    /// [`LeaderboardBuilder::validator`](crate::LeaderboardBuilder::validator).
+    InvalidScore(String),
+    /// A player name was rejected by the name filter before being sent. See
+    /// [`LeaderboardBuilder::name_filter`](crate::LeaderboardBuilder::name_filter).
+    NameRejected(String),
+    /// A player name is already used by another player, with
+    /// [`LeaderboardBuilder::unique_names`](crate::LeaderboardBuilder::unique_names).
+    NameTaken(String),
+    /// Reading or writing local storage failed.
+    Storage(String),
+    /// The operation was cancelled through a [`CancellationToken`](crate::CancellationToken).
+    Cancelled,
+}
+
+impl fmt::Display for JornetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JornetError::Network(error) => write!(f, "network error: {}", error),
+            JornetError::Timeout => write!(f, "request timed out"),
+            JornetError::HttpStatus(status) => write!(f, "server answered with status {}", status),
+            JornetError::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(f, "rate limited, retry after {:?}", retry_after),
+            JornetError::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            JornetError::Deserialization(error) => {
+                write!(f, "error deserializing the response: {}", error)
+            }
+            JornetError::Serialization(error) => write!(f, "error serializing a value: {}", error),
+            JornetError::NotConnectedAsPlayer => write!(f, "not connected as a player"),
+            JornetError::InvalidConfiguration(error) => {
+                write!(f, "invalid configuration: {}", error)
+            }
+            JornetError::InvalidScore(reason) => write!(f, "invalid score: {}", reason),
+            JornetError::NameRejected(reason) => write!(f, "name rejected: {}", reason),
+            JornetError::NameTaken(name) => write!(f, "name already taken: {}", name),
+            JornetError::Storage(error) => write!(f, "storage error: {}", error),
+            JornetError::Cancelled => write!(f, "operation was cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for JornetError {}
+
+impl JornetError {
+    /// Whether the error could be resolved by sending the same request again.
+    pub(crate) fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            JornetError::Network(_)
+                | JornetError::Timeout
+                | JornetError::HttpStatus(500 | 502 | 503 | 504)
+        )
+    }
+}
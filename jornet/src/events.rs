@@ -0,0 +1,52 @@
+use std::{fmt, sync::Arc};
+
+use crate::{JornetError, Player, Score, SubmittedScore};
+
+/// Something that happened while interacting with the leaderboard. Listen to them with
+/// [`LeaderboardBuilder::on_event`](crate::LeaderboardBuilder::on_event).
+#[derive(Debug, Clone)]
+pub enum LeaderboardEvent {
+    /// A player was created.
+    PlayerCreated(Player),
+    /// A score was sent, or kept in the offline queue to be sent later.
+    ScoreSubmitted(SubmittedScore),
+    /// The leaderboard was received.
+    LeaderboardRefreshed(Vec<Score>),
+    /// A request failed, and will be sent again.
+    Retrying {
+        /// The operation being retried.
+        operation: Operation,
+        /// The number of the retry, starting at 1.
+        attempt: u32,
+        /// Why the previous attempt failed.
+        error: JornetError,
+    },
+    /// An operation failed.
+    Failed {
+        /// The operation that failed.
+        operation: Operation,
+        /// Why it failed.
+        error: JornetError,
+    },
+}
+
+/// An operation reported through [`LeaderboardEvent`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// Creating a player.
+    CreatePlayer,
+    /// Sending a score.
+    SubmitScore,
+    /// Getting the leaderboard.
+    RefreshLeaderboard,
+}
+
+/// Callback receiving the events of a leaderboard.
+#[derive(Clone)]
+pub(crate) struct EventListener(pub(crate) Arc<dyn Fn(&LeaderboardEvent) + Send + Sync>);
+
+impl fmt::Debug for EventListener {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EventListener")
+    }
+}
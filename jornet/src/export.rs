@@ -0,0 +1,92 @@
+use std::collections::BTreeSet;
+
+use crate::{JornetError, Score};
+
+/// Export scores to share them outside of the game, like the final leaderboard of a
+/// tournament.
+///
+/// ```
+/// # let scores: Vec<jornet::Score> = vec![];
+/// let csv = jornet::LeaderboardExport::new(&scores).to_csv();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LeaderboardExport<'a> {
+    scores: &'a [Score],
+}
+
+impl<'a> LeaderboardExport<'a> {
+    /// Export `scores`, in the order given.
+    pub fn new(scores: &'a [Score]) -> Self {
+        Self { scores }
+    }
+
+    /// Export to CSV, with a header line. Columns are the rank, player, score, timestamp and
+    /// metadata of each score, followed by a column for each of their dimensions.
+    ///
+    /// Player names and metadata that a spreadsheet would evaluate as a formula are prefixed
+    /// with `'`, as they are chosen by players.
+    pub fn to_csv(&self) -> String {
+        let dimensions: BTreeSet<&str> = self
+            .scores
+            .iter()
+            .flat_map(|score| score.dimensions.keys().map(String::as_str))
+            .collect();
+
+        let mut csv = String::new();
+        let header = ["rank", "player", "score", "timestamp", "meta"]
+            .into_iter()
+            .chain(dimensions.iter().copied())
+            .map(escape)
+            .collect::<Vec<_>>();
+        push_line(&mut csv, &header);
+        for score in self.scores {
+            let mut line = vec![
+                score.rank.map(|rank| rank.to_string()).unwrap_or_default(),
+                escape(&neutralize(&score.player)),
+                match score.score_i64 {
+                    Some(score) => score.to_string(),
+                    None => score.score.to_string(),
+                },
+                escape(&score.timestamp),
+                escape(&neutralize(score.meta.as_deref().unwrap_or_default())),
+            ];
+            line.extend(dimensions.iter().map(|name| {
+                score
+                    .dimension(name)
+                    .map(|value| value.to_string())
+                    .unwrap_or_default()
+            }));
+            push_line(&mut csv, &line);
+        }
+        csv
+    }
+
+    /// Export to pretty-printed JSON, with all the fields of the scores.
+    pub fn to_json(&self) -> Result<String, JornetError> {
+        serde_json::to_string_pretty(self.scores)
+            .map_err(|error| JornetError::Serialization(error.to_string()))
+    }
+}
+
+fn push_line(csv: &mut String, fields: &[String]) {
+    csv.push_str(&fields.join(","));
+    csv.push_str("\r\n");
+}
+
+/// Quote a field if it contains a separator, a quote or a line break.
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Prevent a text field from being evaluated as a formula by spreadsheets.
+fn neutralize(field: &str) -> String {
+    if field.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", field)
+    } else {
+        field.to_string()
+    }
+}
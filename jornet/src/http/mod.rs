@@ -0,0 +1,455 @@
+use std::{fmt, future::Future, sync::Arc, time::Duration};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{Backoff, JornetError};
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native;
+#[cfg(not(target_arch = "wasm32"))]
+mod sse;
+#[cfg(target_arch = "wasm32")]
+mod wasm;
+
+#[cfg(not(target_arch = "wasm32"))]
+use native as platform;
+#[cfg(target_arch = "wasm32")]
+use wasm as platform;
+
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub(crate) use native::block_on;
+pub(crate) use platform::{sleep, spawn, EventSource};
+
+/// Configuration applied to every request sent to the server.
+#[derive(Debug, Clone)]
+pub(crate) struct HttpConfig {
+    /// Hosts to send requests to, in order of preference.
+    pub(crate) hosts: Vec<String>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) max_retries: u32,
+    pub(crate) backoff: Backoff,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) retry_rate_limited: bool,
+    /// Called before each retry, with the number of the retry and the error that caused it.
+    pub(crate) on_retry: Option<RetryHook>,
+    /// Agent used for all requests, so that connections are kept alive and reused.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) agent: ureq::Agent,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            hosts: vec!["https://jornet.vleue.com".to_string()],
+            timeout: None,
+            max_retries: 3,
+            backoff: Backoff::default(),
+            headers: vec![],
+            retry_rate_limited: false,
+            on_retry: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            agent: native::default_agent(),
+        }
+    }
+}
+
+type RetryCallback = dyn Fn(u32, &JornetError) + Send + Sync;
+
+#[derive(Clone)]
+pub(crate) struct RetryHook(pub(crate) Arc<RetryCallback>);
+
+impl fmt::Debug for RetryHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RetryHook")
+    }
+}
+
+/// A response from the server, whatever its status.
+pub(crate) struct Response {
+    pub(crate) status: u16,
+    /// Headers of the response, with lowercase names.
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: Vec<u8>,
+}
+
+impl Response {
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// An event received from a stream of server-sent events.
+pub(crate) struct ServerEvent {
+    /// Type of the event, `message` if the server didn't specify it.
+    pub(crate) event: String,
+    pub(crate) data: String,
+}
+
+/// Change of state of a stream of server-sent events.
+pub(crate) enum StreamUpdate {
+    Connected,
+    Event(ServerEvent),
+    /// The stream was interrupted, it will reconnect.
+    Disconnected(JornetError),
+}
+
+pub(crate) type StreamCallback = dyn Fn(StreamUpdate) + Send + Sync;
+
+/// `Accept` header of requests whose response is parsed, with the formats that can be parsed.
+#[cfg(feature = "msgpack")]
+const ACCEPT_PARSED: (&str, &str) = ("Accept", "application/msgpack, application/json;q=0.9");
+#[cfg(not(feature = "msgpack"))]
+const ACCEPT_PARSED: (&str, &str) = ("Accept", "application/json");
+
+/// Result of a conditional request.
+pub(crate) enum Conditional<T> {
+    /// The resource changed since the given `ETag`.
+    Modified { value: T, etag: Option<String> },
+    /// The resource didn't change since the given `ETag`.
+    NotModified,
+}
+
+pub(crate) async fn get<T: DeserializeOwned>(
+    config: &HttpConfig,
+    path: &str,
+) -> Result<T, JornetError> {
+    with_retries(config, "GET", path, |url| async move {
+        parse(&check(
+            send(config, "GET", &url, &[ACCEPT_PARSED], None).await?,
+        )?)
+    })
+    .await
+}
+
+/// Send a `GET` request with an `If-None-Match` header if an `etag` is given.
+pub(crate) async fn get_if_none_match<T: DeserializeOwned>(
+    config: &HttpConfig,
+    path: &str,
+    etag: Option<&str>,
+) -> Result<Conditional<T>, JornetError> {
+    match get_response_if_none_match(config, path, etag).await? {
+        Conditional::Modified { value, etag } => Ok(Conditional::Modified {
+            value: parse(&value)?,
+            etag,
+        }),
+        Conditional::NotModified => Ok(Conditional::NotModified),
+    }
+}
+
+/// Send a `GET` request with an `If-None-Match` header if an `etag` is given, and return the
+/// response to be parsed by the caller.
+pub(crate) async fn get_response_if_none_match(
+    config: &HttpConfig,
+    path: &str,
+    etag: Option<&str>,
+) -> Result<Conditional<Response>, JornetError> {
+    let mut headers = vec![ACCEPT_PARSED];
+    if let Some(etag) = etag {
+        headers.push(("If-None-Match", etag));
+    }
+    let headers = &headers;
+    with_retries(config, "GET", path, |url| async move {
+        let response = send(config, "GET", &url, headers, None).await?;
+        if response.status == 304 {
+            return Ok(Conditional::NotModified);
+        }
+        let response = check(response)?;
+        let etag = response.header("ETag").map(|etag| etag.to_string());
+        Ok(Conditional::Modified {
+            value: response,
+            etag,
+        })
+    })
+    .await
+}
+
+pub(crate) async fn post<T: Serialize, U: DeserializeOwned>(
+    config: &HttpConfig,
+    path: &str,
+    body: T,
+) -> Result<U, JornetError> {
+    // serializing the body - can't fail
+    let body = serde_json::to_vec(&body).unwrap();
+    let body = &body;
+    with_retries(config, "POST", path, |url| async move {
+        parse(&check(
+            send(config, "POST", &url, &[ACCEPT_PARSED], Some(body)).await?,
+        )?)
+    })
+    .await
+}
+
+pub(crate) async fn delete<T: Serialize>(
+    config: &HttpConfig,
+    path: &str,
+    body: T,
+) -> Result<(), JornetError> {
+    // serializing the body - can't fail
+    let body = serde_json::to_vec(&body).unwrap();
+    let body = &body;
+    with_retries(config, "DELETE", path, |url| async move {
+        check(send(config, "DELETE", &url, &[], Some(body)).await?).map(|_| ())
+    })
+    .await
+}
+
+pub(crate) async fn put<T: Serialize>(
+    config: &HttpConfig,
+    path: &str,
+    body: T,
+) -> Result<(), JornetError> {
+    // serializing the body - can't fail
+    let body = serde_json::to_vec(&body).unwrap();
+    let body = &body;
+    with_retries(config, "PUT", path, |url| async move {
+        check(send(config, "PUT", &url, &[], Some(body)).await?).map(|_| ())
+    })
+    .await
+}
+
+/// Send a `GET` request, and return the raw body of the response.
+pub(crate) async fn get_bytes(config: &HttpConfig, path: &str) -> Result<Vec<u8>, JornetError> {
+    with_retries(config, "GET", path, |url| async move {
+        Ok(check(send(config, "GET", &url, &[], None).await?)?.body)
+    })
+    .await
+}
+
+/// Send a `PUT` request with a raw body.
+pub(crate) async fn put_bytes(
+    config: &HttpConfig,
+    path: &str,
+    headers: &[(&str, &str)],
+    body: &[u8],
+) -> Result<(), JornetError> {
+    with_retries(config, "PUT", path, |url| async move {
+        check(send(config, "PUT", &url, headers, Some(body)).await?).map(|_| ())
+    })
+    .await
+}
+
+/// Send a `POST` request that must not be retried, as it may not be idempotent.
+pub(crate) async fn post_once<T: Serialize, U: DeserializeOwned>(
+    config: &HttpConfig,
+    path: &str,
+    body: T,
+) -> Result<U, JornetError> {
+    let config = HttpConfig {
+        max_retries: 0,
+        ..config.clone()
+    };
+    post(&config, path, body).await
+}
+
+/// Send a single `GET` request, and measure how long it took to receive a successful response.
+pub(crate) async fn ping(config: &HttpConfig, path: &str) -> Result<Duration, JornetError> {
+    let config = HttpConfig {
+        max_retries: 0,
+        ..config.clone()
+    };
+    let config = &config;
+    with_retries(config, "GET", path, |url| async move {
+        let start = now_millis();
+        check(send(config, "GET", &url, &[], None).await?)?;
+        Ok(Duration::from_secs_f64(
+            (now_millis() - start).max(0.0) / 1000.0,
+        ))
+    })
+    .await
+}
+
+/// Open a stream of server-sent events, reconnecting when it's interrupted and resuming after
+/// the last event received. The stream is closed when the returned [`EventSource`] is dropped.
+pub(crate) fn event_source(
+    config: &HttpConfig,
+    path: &str,
+    on_update: Arc<StreamCallback>,
+) -> EventSource {
+    platform::event_source(config, path, on_update)
+}
+
+/// Build the query string of a URL, including the leading `?` if there are parameters.
+pub(crate) fn query_string(parameters: &[(&str, String)]) -> String {
+    let query = parameters
+        .iter()
+        .map(|(name, value)| format!("{}={}", percent_encode(name), percent_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&");
+    if query.is_empty() {
+        query
+    } else {
+        format!("?{}", query)
+    }
+}
+
+/// Encode a query parameter, keeping only unreserved characters as is.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+async fn send(
+    config: &HttpConfig,
+    method: &'static str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&[u8]>,
+) -> Result<Response, JornetError> {
+    let response = platform::send(config, method, url, headers, body).await?;
+    #[cfg(feature = "tracing")]
+    tracing::debug!(status = response.status, "received response");
+    Ok(response)
+}
+
+/// Turn error statuses into errors.
+fn check(response: Response) -> Result<Response, JornetError> {
+    match response.status {
+        200..=299 => Ok(response),
+        429 => Err(JornetError::RateLimited {
+            retry_after: parse_retry_after(response.header("Retry-After")),
+        }),
+        status => Err(JornetError::HttpStatus(status)),
+    }
+}
+
+/// Parse the body of a response, in the format given by its `Content-Type`.
+pub(crate) fn parse<T: DeserializeOwned>(response: &Response) -> Result<T, JornetError> {
+    #[cfg(feature = "msgpack")]
+    if is_msgpack(response) {
+        return rmp_serde::from_slice(&response.body)
+            .map_err(|error| JornetError::Deserialization(error.to_string()));
+    }
+    serde_json::from_slice(&response.body)
+        .map_err(|error| JornetError::Deserialization(error.to_string()))
+}
+
+/// Parse the body of a response into an existing value, reusing its allocations.
+pub(crate) fn parse_in_place<'de, T: Deserialize<'de>>(
+    response: &'de Response,
+    place: &mut T,
+) -> Result<(), JornetError> {
+    #[cfg(feature = "msgpack")]
+    if is_msgpack(response) {
+        let mut deserializer = rmp_serde::Deserializer::from_read_ref(&response.body);
+        return T::deserialize_in_place(&mut deserializer, place)
+            .map_err(|error| JornetError::Deserialization(error.to_string()));
+    }
+    let mut deserializer = serde_json::Deserializer::from_slice(&response.body);
+    T::deserialize_in_place(&mut deserializer, place)
+        .and_then(|_| deserializer.end())
+        .map_err(|error| JornetError::Deserialization(error.to_string()))
+}
+
+#[cfg(feature = "msgpack")]
+fn is_msgpack(response: &Response) -> bool {
+    let content_type = response
+        .header("Content-Type")
+        .and_then(|content_type| content_type.split(';').next())
+        .map(str::trim);
+    matches!(
+        content_type,
+        Some("application/msgpack" | "application/x-msgpack")
+    )
+}
+
+/// Parse the value of a `Retry-After` header, when given as a number of seconds.
+fn parse_retry_after(header: Option<&str>) -> Option<Duration> {
+    header
+        .and_then(|value| value.trim().parse().ok())
+        .map(Duration::from_secs)
+}
+
+/// Send a request, trying each host in order while they are unreachable, and retrying
+/// transient errors. Absolute URLs are sent as is instead.
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+async fn with_retries<T, F, Fut>(
+    config: &HttpConfig,
+    method: &str,
+    path: &str,
+    mut request: F,
+) -> Result<T, JornetError>
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<T, JornetError>>,
+{
+    let no_host = [String::new()];
+    let hosts = if path.starts_with("http://") || path.starts_with("https://") {
+        &no_host[..]
+    } else {
+        &config.hosts[..]
+    };
+    let retrying = async {
+        let mut attempt = 0;
+        loop {
+            let mut result = Err(JornetError::InvalidConfiguration(
+                "no host configured".to_string(),
+            ));
+            for host in hosts {
+                #[cfg(feature = "tracing")]
+                let start = now_millis();
+                result = request(format!("{}{}", host, path)).await;
+                #[cfg(feature = "tracing")]
+                {
+                    let duration_ms = now_millis() - start;
+                    match &result {
+                        Ok(_) => tracing::debug!(attempt, host, duration_ms, "request succeeded"),
+                        Err(error) => {
+                            tracing::warn!(attempt, host, duration_ms, %error, "request failed")
+                        }
+                    }
+                }
+                // only fail over to the next host if this one couldn't be reached
+                if !matches!(result, Err(JornetError::Network(_) | JornetError::Timeout)) {
+                    break;
+                }
+            }
+            let delay = match &result {
+                // a server asking for a longer delay than the backoff allows is not waited for
+                Err(JornetError::RateLimited { retry_after })
+                    if config.retry_rate_limited
+                        && attempt < config.max_retries
+                        && retry_after.is_none_or(|delay| delay <= config.backoff.max_delay()) =>
+                {
+                    retry_after.unwrap_or_else(|| config.backoff.delay(attempt))
+                }
+                Err(error) if error.is_retryable() && attempt < config.max_retries => {
+                    config.backoff.delay(attempt)
+                }
+                _ => return result,
+            };
+            attempt += 1;
+            if let (Some(on_retry), Err(error)) = (&config.on_retry, &result) {
+                (on_retry.0)(attempt, error);
+            }
+            platform::sleep(delay).await;
+        }
+    };
+    #[cfg(feature = "tracing")]
+    let retrying =
+        tracing::Instrument::instrument(retrying, tracing::debug_span!("request", method, path));
+
+    retrying.await
+}
+
+fn now_millis() -> f64 {
+    #[cfg(not(target_arch = "wasm32"))]
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs_f64()
+        * 1000.0;
+    #[cfg(target_arch = "wasm32")]
+    let now = js_sys::Date::now();
+
+    now
+}
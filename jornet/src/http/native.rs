@@ -0,0 +1,324 @@
+use std::{
+    future::Future,
+    io::{BufRead, BufReader, Read},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, Mutex, OnceLock,
+    },
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::{Duration, Instant},
+};
+
+use crate::JornetError;
+
+use super::{sse::EventParser, HttpConfig, Response, StreamCallback, StreamUpdate};
+
+pub(super) async fn send(
+    config: &HttpConfig,
+    method: &'static str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&[u8]>,
+) -> Result<Response, JornetError> {
+    let mut request = build_request(config, method, url);
+    // bodies are JSON, unless another content type is given
+    if body.is_some() {
+        request = request.set("Content-Type", "application/json");
+    }
+    for (name, value) in headers {
+        request = request.set(name, value);
+    }
+    let body = body.map(|body| body.to_vec());
+    run_blocking(move || {
+        read_response(match body {
+            Some(body) => request.send_bytes(&body),
+            None => request.call(),
+        })
+    })
+    .await
+}
+
+pub(crate) async fn sleep(duration: Duration) {
+    let deadline = Instant::now() + duration;
+    std::future::poll_fn(|cx| {
+        if Instant::now() >= deadline {
+            return Poll::Ready(());
+        }
+        let timers = timers();
+        timers
+            .sleeps
+            .lock()
+            .unwrap()
+            .push((deadline, cx.waker().clone()));
+        timers.changed.notify_one();
+        Poll::Pending
+    })
+    .await
+}
+
+/// Pending sleeps, woken at their deadline by a single timer thread.
+#[derive(Default)]
+struct Timers {
+    sleeps: Mutex<Vec<(Instant, Waker)>>,
+    /// Notified when a sleep is added, as it can end before the others.
+    changed: Condvar,
+}
+
+fn timers() -> &'static Timers {
+    static TIMERS: OnceLock<Timers> = OnceLock::new();
+    TIMERS.get_or_init(|| {
+        thread::spawn(|| run_timers(timers()));
+        Timers::default()
+    })
+}
+
+fn run_timers(timers: &Timers) {
+    loop {
+        let mut sleeps = timers.sleeps.lock().unwrap();
+        let now = Instant::now();
+        let (ended, waiting): (Vec<_>, Vec<_>) =
+            sleeps.drain(..).partition(|(deadline, _)| *deadline <= now);
+        *sleeps = waiting;
+        if ended.is_empty() {
+            let next = sleeps.iter().map(|(deadline, _)| *deadline).min();
+            let _sleeps = match next {
+                Some(next) => timers.changed.wait_timeout(sleeps, next - now).unwrap().0,
+                None => timers.changed.wait(sleeps).unwrap(),
+            };
+            continue;
+        }
+        // wakers are called without the lock, in case they add a sleep
+        drop(sleeps);
+        for (_, waker) in ended {
+            waker.wake();
+        }
+    }
+}
+
+/// A stream of server-sent events, read on its own thread.
+pub(crate) struct EventSource {
+    closed: Arc<AtomicBool>,
+}
+
+impl Drop for EventSource {
+    fn drop(&mut self) {
+        // the thread stops after the next event, or when the connection ends
+        self.closed.store(true, Ordering::Relaxed);
+    }
+}
+
+pub(super) fn event_source(
+    config: &HttpConfig,
+    path: &str,
+    on_update: Arc<StreamCallback>,
+) -> EventSource {
+    let closed = Arc::new(AtomicBool::new(false));
+    let thread_closed = closed.clone();
+    let config = config.clone();
+    let path = path.to_string();
+    thread::spawn(move || {
+        let mut parser = EventParser::default();
+        let mut attempt = 0;
+        while !thread_closed.load(Ordering::Relaxed) {
+            // hosts are tried in turn while they can't be reached
+            let Some(host) = config
+                .hosts
+                .get(attempt as usize % config.hosts.len().max(1))
+            else {
+                on_update(StreamUpdate::Disconnected(
+                    JornetError::InvalidConfiguration("no host configured".to_string()),
+                ));
+                return;
+            };
+            let url = format!("{}{}", host, path);
+            let error = match open_stream(&config, &url, parser.last_event_id.as_deref()) {
+                Ok(reader) => {
+                    attempt = 0;
+                    on_update(StreamUpdate::Connected);
+                    read_events(reader, &mut parser, &thread_closed, &*on_update)
+                }
+                Err(error) => error,
+            };
+            if thread_closed.load(Ordering::Relaxed) {
+                return;
+            }
+            on_update(StreamUpdate::Disconnected(error));
+            let delay = parser
+                .retry
+                .unwrap_or_else(|| config.backoff.delay(attempt));
+            attempt += 1;
+            thread::sleep(delay);
+        }
+    });
+    EventSource { closed }
+}
+
+/// Connect to a stream of server-sent events, resuming after `last_event_id` if given.
+fn open_stream(
+    config: &HttpConfig,
+    url: &str,
+    last_event_id: Option<&str>,
+) -> Result<impl BufRead, JornetError> {
+    // the timeout is not set, as it applies to the whole stream
+    let mut request = config.agent.get(url).set("Accept", "text/event-stream");
+    for (name, value) in &config.headers {
+        request = request.set(name, value);
+    }
+    if let Some(last_event_id) = last_event_id {
+        request = request.set("Last-Event-ID", last_event_id);
+    }
+    match request.call() {
+        Ok(response) => Ok(BufReader::new(response.into_reader())),
+        Err(error) => Err(match read_response(Err(error)).and_then(super::check) {
+            Err(error) => error,
+            Ok(response) => JornetError::HttpStatus(response.status),
+        }),
+    }
+}
+
+/// Read events until the stream ends or is closed, and return why it ended.
+fn read_events(
+    reader: impl BufRead,
+    parser: &mut EventParser,
+    closed: &AtomicBool,
+    on_update: &StreamCallback,
+) -> JornetError {
+    for line in reader.lines() {
+        if closed.load(Ordering::Relaxed) {
+            return JornetError::Cancelled;
+        }
+        match line {
+            Ok(line) => {
+                if let Some(event) = parser.feed(&line) {
+                    on_update(StreamUpdate::Event(event));
+                }
+            }
+            Err(error) => return JornetError::Network(error.to_string()),
+        }
+    }
+    JornetError::Network("the stream was closed by the server".to_string())
+}
+
+/// Run a future on a new thread, until it completes.
+pub(crate) fn spawn(future: impl Future<Output = ()> + Send + 'static) {
+    thread::spawn(move || block_on(future));
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run a future to completion, blocking the current thread.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Run a blocking function on a new thread, and wait for its result without blocking.
+///
+/// If the returned future is dropped, the function will still run to completion but its result
+/// will be discarded.
+fn run_blocking<T: Send + 'static>(
+    f: impl FnOnce() -> T + Send + 'static,
+) -> impl Future<Output = T> {
+    let shared: Arc<Mutex<(Option<T>, Option<Waker>)>> = Arc::new(Mutex::new((None, None)));
+    let thread_shared = shared.clone();
+    thread::spawn(move || {
+        let result = f();
+        let mut shared = thread_shared.lock().unwrap();
+        shared.0 = Some(result);
+        if let Some(waker) = shared.1.take() {
+            waker.wake();
+        }
+    });
+    std::future::poll_fn(move |cx| {
+        let mut shared = shared.lock().unwrap();
+        match shared.0.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.1 = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    })
+}
+
+fn build_request(config: &HttpConfig, method: &str, url: &str) -> ureq::Request {
+    let mut request = config.agent.request(method, url);
+    if let Some(timeout) = config.timeout {
+        request = request.timeout(timeout);
+    }
+    for (name, value) in &config.headers {
+        request = request.set(name, value);
+    }
+    request
+}
+
+#[cfg(feature = "native-tls")]
+pub(super) fn default_agent() -> ureq::Agent {
+    match native_tls::TlsConnector::new() {
+        Ok(connector) => ureq::AgentBuilder::new()
+            .tls_connector(Arc::new(connector))
+            .build(),
+        // without a TLS connector, requests over https will fail with a network error
+        Err(_) => ureq::agent(),
+    }
+}
+
+#[cfg(not(feature = "native-tls"))]
+pub(super) fn default_agent() -> ureq::Agent {
+    ureq::agent()
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<Response, JornetError> {
+    let response = match response {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(ureq::Error::Transport(transport)) => {
+            let timed_out = std::error::Error::source(&transport)
+                .and_then(|source| source.downcast_ref::<std::io::Error>())
+                .map(|error| {
+                    matches!(
+                        error.kind(),
+                        std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock
+                    )
+                })
+                .unwrap_or(false);
+            return if timed_out {
+                Err(JornetError::Timeout)
+            } else {
+                Err(JornetError::Network(transport.to_string()))
+            };
+        }
+    };
+    let status = response.status();
+    let headers = response
+        .headers_names()
+        .into_iter()
+        .filter_map(|name| {
+            let value = response.header(&name)?.to_string();
+            Some((name, value))
+        })
+        .collect();
+    let mut body = vec![];
+    response
+        .into_reader()
+        .read_to_end(&mut body)
+        .map_err(|error| JornetError::Network(error.to_string()))?;
+    Ok(Response {
+        status,
+        headers,
+        body,
+    })
+}
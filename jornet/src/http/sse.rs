@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use super::ServerEvent;
+
+/// Parser of a stream of server-sent events, fed one line at a time.
+#[derive(Debug, Default)]
+pub(super) struct EventParser {
+    event: Option<String>,
+    data: Vec<String>,
+    /// ID of the last event received, sent when reconnecting to resume the stream.
+    pub(super) last_event_id: Option<String>,
+    /// Delay before reconnecting, if requested by the server.
+    pub(super) retry: Option<Duration>,
+}
+
+impl EventParser {
+    /// Parse a line, returning the event it completes if any.
+    pub(super) fn feed(&mut self, line: &str) -> Option<ServerEvent> {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.is_empty() {
+            let event = self.event.take();
+            if self.data.is_empty() {
+                return None;
+            }
+            return Some(ServerEvent {
+                event: event.unwrap_or_else(|| "message".to_string()),
+                data: std::mem::take(&mut self.data).join("\n"),
+            });
+        }
+        // lines starting with a colon are comments, used by servers to keep the stream alive
+        if line.starts_with(':') {
+            return None;
+        }
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "event" => self.event = Some(value.to_string()),
+            "data" => self.data.push(value.to_string()),
+            "id" if !value.contains('\0') => self.last_event_id = Some(value.to_string()),
+            "retry" => {
+                if let Ok(millis) = value.parse() {
+                    self.retry = Some(Duration::from_millis(millis));
+                }
+            }
+            _ => (),
+        }
+        None
+    }
+}
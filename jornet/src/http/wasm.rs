@@ -0,0 +1,240 @@
+use std::{sync::Arc, time::Duration};
+
+use wasm_bindgen::{closure::Closure, JsCast};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{AbortController, Headers, MessageEvent, Request, RequestInit};
+
+use crate::JornetError;
+
+use super::{HttpConfig, Response, ServerEvent, StreamCallback, StreamUpdate};
+
+/// Send a request with `fetch`. Compression of the response is negotiated and decoded by the
+/// browser.
+pub(super) async fn send(
+    config: &HttpConfig,
+    method: &'static str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&[u8]>,
+) -> Result<Response, JornetError> {
+    // building headers - can't fail
+    let request_headers = Headers::new().unwrap();
+    // bodies are JSON, unless another content type is given
+    if body.is_some() {
+        // setting a header - can't fail
+        request_headers
+            .set("Content-Type", "application/json")
+            .unwrap();
+    }
+    for (name, value) in config
+        .headers
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.as_str()))
+        .chain(headers.iter().copied())
+    {
+        // can fail if the header is not valid
+        request_headers
+            .set(name, value)
+            .map_err(|error| JornetError::Network(format!("{:?}", error)))?;
+    }
+    let mut opts = RequestInit::new();
+    opts.method(method);
+    if let Some(body) = body {
+        opts.body(Some(&js_sys::Uint8Array::from(body)));
+    }
+    opts.headers(&request_headers);
+    // the fetch is aborted if this future is dropped before completing, or on timeout
+    // creating an abort controller - can't fail
+    let abort = AbortOnDrop(AbortController::new().unwrap());
+    opts.signal(Some(&abort.0.signal()));
+    let _timeout = config
+        .timeout
+        .map(|timeout| AbortAfter::new(abort.0.clone(), timeout));
+    // the signal is only aborted by the timeout while this future is running
+    let timed_out = |error| {
+        if abort.0.signal().aborted() {
+            JornetError::Timeout
+        } else {
+            JornetError::Network(format!("{:?}", error))
+        }
+    };
+
+    // building the request - can't fail
+    let request = Request::new_with_str_and_init(url, &opts).unwrap();
+
+    // getting the window - can't fail
+    let window = web_sys::window().unwrap();
+    // can fail on network error or timeout
+    let resp_value = JsFuture::from(window.fetch_with_request(&request))
+        .await
+        .map_err(timed_out)?;
+    // converting the JsValue to the correct type - can't fail
+    let resp: web_sys::Response = resp_value.dyn_into().unwrap();
+
+    let mut headers = vec![];
+    // headers are iterable - can't fail
+    if let Some(entries) = js_sys::try_iter(resp.headers().as_ref()).unwrap() {
+        for entry in entries.flatten() {
+            let entry: js_sys::Array = entry.unchecked_into();
+            if let (Some(name), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string())
+            {
+                headers.push((name.to_lowercase(), value));
+            }
+        }
+    }
+    // reading the body - can fail on network error or timeout
+    let body = JsFuture::from(resp.array_buffer().unwrap())
+        .await
+        .map_err(timed_out)?;
+
+    Ok(Response {
+        status: resp.status(),
+        headers,
+        body: js_sys::Uint8Array::new(&body).to_vec(),
+    })
+}
+
+/// A stream of server-sent events, read by the browser.
+pub(crate) struct EventSource {
+    source: Option<web_sys::EventSource>,
+    _callbacks: Vec<Closure<dyn FnMut(web_sys::Event)>>,
+}
+
+impl Drop for EventSource {
+    fn drop(&mut self) {
+        if let Some(source) = &self.source {
+            source.close();
+        }
+    }
+}
+
+/// Open a stream with the browser `EventSource`, that reconnects by itself and resumes after the
+/// last event received. It can't send the headers configured on the leaderboard.
+pub(super) fn event_source(
+    config: &HttpConfig,
+    path: &str,
+    on_update: Arc<StreamCallback>,
+) -> EventSource {
+    let source = config
+        .hosts
+        .first()
+        .ok_or_else(|| JornetError::InvalidConfiguration("no host configured".to_string()))
+        .and_then(|host| {
+            web_sys::EventSource::new(&format!("{}{}", host, path))
+                .map_err(|error| JornetError::InvalidConfiguration(format!("{:?}", error)))
+        });
+    let source = match source {
+        Ok(source) => source,
+        Err(error) => {
+            on_update(StreamUpdate::Disconnected(error));
+            return EventSource {
+                source: None,
+                _callbacks: vec![],
+            };
+        }
+    };
+
+    let on_open = {
+        let on_update = on_update.clone();
+        Closure::<dyn FnMut(web_sys::Event)>::new(move |_| on_update(StreamUpdate::Connected))
+    };
+    let on_error = {
+        let on_update = on_update.clone();
+        Closure::<dyn FnMut(web_sys::Event)>::new(move |_| {
+            on_update(StreamUpdate::Disconnected(JornetError::Network(
+                "the stream was interrupted".to_string(),
+            )))
+        })
+    };
+    let on_event = Closure::<dyn FnMut(web_sys::Event)>::new(move |event: web_sys::Event| {
+        let event: &MessageEvent = event.unchecked_ref();
+        if let Some(data) = event.data().as_string() {
+            on_update(StreamUpdate::Event(ServerEvent {
+                event: event.type_(),
+                data,
+            }));
+        }
+    });
+    source.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    source.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+    source.set_onmessage(Some(on_event.as_ref().unchecked_ref()));
+    // adding a listener - can't fail
+    source
+        .add_event_listener_with_callback("score", on_event.as_ref().unchecked_ref())
+        .unwrap();
+
+    EventSource {
+        source: Some(source),
+        _callbacks: vec![on_open, on_error, on_event],
+    }
+}
+
+/// Run a future on the current thread, until it completes.
+pub(crate) fn spawn(future: impl std::future::Future<Output = ()> + 'static) {
+    wasm_bindgen_futures::spawn_local(future);
+}
+
+pub(crate) async fn sleep(duration: Duration) {
+    let _ = JsFuture::from(timer(duration)).await;
+}
+
+fn timer(duration: Duration) -> js_sys::Promise {
+    js_sys::Promise::new(&mut |resolve, _| {
+        // getting the window - can't fail
+        web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                &resolve,
+                duration_millis(duration),
+            )
+            // setting a timeout - can't fail
+            .unwrap();
+    })
+}
+
+/// Abort a fetch after a timeout, unless dropped before.
+struct AbortAfter {
+    handle: i32,
+    _abort: Closure<dyn FnMut()>,
+}
+
+impl AbortAfter {
+    fn new(controller: AbortController, timeout: Duration) -> Self {
+        let abort = Closure::once(move || controller.abort());
+        // getting the window - can't fail
+        let handle = web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                abort.as_ref().unchecked_ref(),
+                duration_millis(timeout),
+            )
+            // setting a timeout - can't fail
+            .unwrap();
+        Self {
+            handle,
+            _abort: abort,
+        }
+    }
+}
+
+impl Drop for AbortAfter {
+    fn drop(&mut self) {
+        // getting the window - can't fail
+        web_sys::window()
+            .unwrap()
+            .clear_timeout_with_handle(self.handle);
+    }
+}
+
+fn duration_millis(duration: Duration) -> i32 {
+    duration.as_millis().min(i32::MAX as u128) as i32
+}
+
+/// Abort the underlying fetch when dropped.
+struct AbortOnDrop(AbortController);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{timestamp, ScoreOrder};
+
+/// Metadata of a leaderboard, as configured on the dashboard.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LeaderboardInfo {
+    /// ID of the leaderboard.
+    pub id: Uuid,
+    /// Human-readable name of the leaderboard.
+    pub name: String,
+    /// When the leaderboard was created, in RFC 3339 format, if the server exposes it.
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// Number of scores in the leaderboard.
+    #[serde(default, alias = "scores")]
+    pub score_count: u64,
+    /// Which scores are better in the leaderboard, if the server exposes it.
+    #[serde(default)]
+    pub order: Option<ScoreOrder>,
+}
+
+impl LeaderboardInfo {
+    /// When the leaderboard was created, in seconds since the Unix epoch.
+    pub fn created_unix_timestamp(&self) -> Option<i64> {
+        self.created_at
+            .as_deref()
+            .and_then(timestamp::parse_rfc3339)
+    }
+}
+
+/// Number of scores and players of a leaderboard, without the scores themselves.
+#[derive(Deserialize, Debug, Clone, Copy, Default)]
+pub(crate) struct Counts {
+    #[serde(default)]
+    pub(crate) scores: u64,
+    #[serde(default)]
+    pub(crate) players: u64,
+}
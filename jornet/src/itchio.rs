@@ -0,0 +1,28 @@
+/// Name of the environment variable set by the itch.io app for the games it launches.
+#[cfg(not(target_arch = "wasm32"))]
+const API_KEY_VARIABLE: &str = "ITCHIO_API_KEY";
+
+/// Name of the query parameter of the game frame holding the key on wasm.
+#[cfg(target_arch = "wasm32")]
+const API_KEY_PARAMETER: &str = "api_key";
+
+/// The itch.io API key of the user playing the game, if the game was given one.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn api_key() -> Option<String> {
+    std::env::var(API_KEY_VARIABLE)
+        .ok()
+        .filter(|key| !key.is_empty())
+}
+
+/// The itch.io API key of the user playing the game, if the game was given one.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn api_key() -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(name, _)| *name == API_KEY_PARAMETER)
+        .map(|(_, key)| key.to_string())
+        .filter(|key| !key.is_empty())
+}
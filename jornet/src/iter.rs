@@ -0,0 +1,79 @@
+use crate::{anonymize::Anonymizer, JornetError, Score};
+
+/// Scores of a leaderboard, parsed one at a time as they are iterated over.
+///
+/// Parsing a large leaderboard at once can take long enough to drop frames. With this, scores
+/// can be parsed a few at a time over several frames, without allocating all of them.
+pub struct ScoreIter {
+    body: Vec<u8>,
+    position: usize,
+    done: bool,
+    anonymizer: Anonymizer,
+}
+
+impl ScoreIter {
+    pub(crate) fn new(body: Vec<u8>, anonymizer: Anonymizer) -> Result<Self, JornetError> {
+        let mut iter = Self {
+            body,
+            position: 0,
+            done: false,
+            anonymizer,
+        };
+        iter.skip_whitespace();
+        if iter.peek() != Some(b'[') {
+            return Err(JornetError::Deserialization(
+                "expected an array of scores".to_string(),
+            ));
+        }
+        iter.position += 1;
+        iter.skip_whitespace();
+        if iter.peek() == Some(b']') {
+            iter.done = true;
+        }
+        Ok(iter)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.body.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn fail(&mut self, error: String) -> Option<Result<Score, JornetError>> {
+        self.done = true;
+        Some(Err(JornetError::Deserialization(error)))
+    }
+}
+
+impl Iterator for ScoreIter {
+    type Item = Result<Score, JornetError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut scores =
+            serde_json::Deserializer::from_slice(&self.body[self.position..]).into_iter::<Score>();
+        let mut score = match scores.next() {
+            Some(Ok(score)) => score,
+            Some(Err(error)) => return self.fail(error.to_string()),
+            None => return self.fail("unexpected end of the scores".to_string()),
+        };
+        self.position += scores.byte_offset();
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b',') => {
+                self.position += 1;
+                self.skip_whitespace();
+            }
+            Some(b']') => self.done = true,
+            _ => return self.fail("expected `,` or `]` after a score".to_string()),
+        }
+        self.anonymizer.apply_one(&mut score);
+        Some(Ok(score))
+    }
+}
@@ -0,0 +1,38 @@
+use std::{
+    future::{poll_fn, Future},
+    pin::Pin,
+    task::Poll,
+};
+
+/// Run futures concurrently, with at most `limit` of them running at the same time. Outputs are
+/// returned in the same order as the futures.
+pub(crate) async fn join_limited<F: Future>(futures: Vec<F>, limit: usize) -> Vec<F::Output> {
+    let mut results: Vec<Option<F::Output>> = futures.iter().map(|_| None).collect();
+    let mut pending = futures.into_iter().enumerate();
+    let mut running: Vec<(usize, Pin<Box<F>>)> = vec![];
+    poll_fn(|cx| loop {
+        while running.len() < limit.max(1) {
+            match pending.next() {
+                Some((index, future)) => running.push((index, Box::pin(future))),
+                None => break,
+            }
+        }
+        if running.is_empty() {
+            // every future completed - can't fail
+            return Poll::Ready(results.drain(..).map(|result| result.unwrap()).collect());
+        }
+        let before = running.len();
+        running.retain_mut(|(index, future)| match future.as_mut().poll(cx) {
+            Poll::Ready(output) => {
+                results[*index] = Some(output);
+                false
+            }
+            Poll::Pending => true,
+        });
+        // start the next futures if some completed, otherwise wait to be woken up
+        if running.len() == before {
+            return Poll::Pending;
+        }
+    })
+    .await
+}
@@ -1,31 +1,370 @@
+mod anonymize;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
+mod builder;
+mod cancel;
+mod client;
+mod coalesce;
+mod credentials;
+mod diff;
+mod error;
+mod events;
+#[cfg(feature = "export")]
+mod export;
 mod http;
+mod info;
+#[cfg(feature = "itchio")]
+mod itchio;
+mod iter;
+mod join;
+mod names;
+mod order;
+mod pages;
+mod profile;
+mod query;
+mod queue;
+mod recovery;
+mod refresh;
+mod season;
+mod session;
+mod snapshot;
+mod stats;
+mod storage;
+mod stream;
+mod submission;
+mod team;
+mod timestamp;
+mod view;
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use hmac::{Hmac, Mac};
-use serde::{Deserialize, Serialize};
-use sha2::Sha256;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
+use anonymize::Anonymizer;
+pub use anonymize::PlayerNames;
+pub use builder::{Backoff, LeaderboardBuilder};
+pub use cancel::CancellationToken;
+pub use client::JornetClient;
+use coalesce::Coalescer;
+pub use credentials::CredentialProtection;
+pub use diff::{LeaderboardDiff, RankChange};
+pub use error::JornetError;
+use events::EventListener;
+pub use events::{LeaderboardEvent, Operation};
+#[cfg(feature = "export")]
+pub use export::LeaderboardExport;
+use http::{Conditional, HttpConfig, RetryHook};
+use info::Counts;
+pub use info::LeaderboardInfo;
+pub use iter::ScoreIter;
+pub use names::{NameFilter, NameGenerator};
+pub use order::ScoreOrder;
+pub use pages::ScorePages;
+pub use profile::PlayerProfile;
+pub use query::{ScoreQuery, TimeWindow};
+use queue::OfflineQueue;
+use recovery::{Recovery, RecoveryCode, RecoveryRequest};
+pub use refresh::AutoRefresh;
+pub use season::Season;
+use session::{Session, SessionRequest};
+use snapshot::SnapshotCache;
+pub use stats::{HistogramBucket, LeaderboardStats, PlayerStats, ScorePlacement};
+pub use stream::ScoreStream;
+pub use submission::ScoreSubmission;
+use submission::Validator;
+pub use team::{Aggregation, TeamScore};
+#[cfg(not(target_arch = "wasm32"))]
+pub use ureq;
+pub use view::LeaderboardView;
+
+/// Maximum number of scores sent at the same time by [`Leaderboard::send_scores`].
+const MAX_CONCURRENT_SUBMISSIONS: usize = 4;
+
+/// Number of names generated for a player with [`LeaderboardBuilder::unique_names`] before
+/// giving up if they are all taken.
+const GENERATED_NAME_ATTEMPTS: usize = 5;
+
 /// Used to interact with Jornet leaderboard.
 pub struct Leaderboard {
     id: Uuid,
     key: Uuid,
-    host: String,
     player: Option<Player>,
+    /// Other players connected on this device, that scores can be sent as.
+    local_players: Vec<Player>,
+    /// Where the current player is saved, if it was connected with
+    /// [`Self::connect_or_create`].
+    player_location: Option<String>,
+    credential_protection: CredentialProtection,
+    http: HttpConfig,
+    cache: Mutex<Option<CachedLeaderboard>>,
+    /// `ETag` of the last leaderboard received with [`Self::get_leaderboard_into`].
+    into_etag: Mutex<Option<String>>,
+    /// Requests for scores being sent, shared by concurrent calls.
+    requests: Coalescer<Vec<Score>>,
+    /// Whether scores are sent with an idempotency key.
+    idempotency_keys: bool,
+    /// Whether scores are sent with a nonce.
+    nonces: bool,
+    /// Whether scores are signed with session tokens instead of the key of the player.
+    session_tokens: bool,
+    /// Current session of each player, by ID.
+    sessions: Mutex<HashMap<Uuid, Session>>,
+    session_requests: Coalescer<Session>,
+    /// Where scores that couldn't be sent are kept, if enabled.
+    queue: Option<OfflineQueue>,
+    /// Where the last leaderboard received is kept, if enabled.
+    snapshot: Option<SnapshotCache>,
+    order: ScoreOrder,
+    player_names: PlayerNames,
+    name_generator: Option<NameGenerator>,
+    name_filter: Option<NameFilter>,
+    unique_names: bool,
+    listener: Option<EventListener>,
+    validators: Vec<Validator>,
+}
+
+/// Last leaderboard received, with its `ETag`.
+struct CachedLeaderboard {
+    etag: String,
+    scores: Vec<Score>,
 }
 
 impl Leaderboard {
     pub fn with_host_and_leaderboard(host: Option<String>, id: Uuid, key: Uuid) -> Self {
+        let mut http = HttpConfig::default();
+        if let Some(host) = host {
+            http.hosts = vec![host];
+        }
+        Self::new(id, key, http)
+    }
+
+    /// Start building a leaderboard, to configure timeouts and retries of requests.
+    pub fn builder(id: Uuid, key: Uuid) -> LeaderboardBuilder {
+        LeaderboardBuilder::new(id, key)
+    }
+
+    /// Create a leaderboard configured from environment variables. See
+    /// [`LeaderboardBuilder::from_env`] for the variables used.
+    pub fn from_env() -> Result<Self, JornetError> {
+        Ok(LeaderboardBuilder::from_env()?.build())
+    }
+
+    pub(crate) fn new(id: Uuid, key: Uuid, http: HttpConfig) -> Self {
         Self {
             id,
             key,
-            host: host.unwrap_or_else(|| "https://jornet.vleue.com".to_string()),
             player: Default::default(),
+            local_players: vec![],
+            player_location: None,
+            credential_protection: CredentialProtection::default(),
+            http,
+            cache: Default::default(),
+            into_etag: Default::default(),
+            requests: Default::default(),
+            idempotency_keys: false,
+            nonces: false,
+            session_tokens: false,
+            sessions: Default::default(),
+            session_requests: Default::default(),
+            queue: None,
+            snapshot: None,
+            order: ScoreOrder::default(),
+            player_names: PlayerNames::default(),
+            name_generator: None,
+            name_filter: None,
+            unique_names: false,
+            listener: None,
+            validators: vec![],
         }
     }
 
+    /// Add a header that will be sent with every following request.
+    pub fn add_header(&mut self, name: &str, value: &str) {
+        self.http
+            .headers
+            .push((name.to_string(), value.to_string()));
+    }
+
+    /// Check if the server is reachable, and measure the round trip time of a request.
+    ///
+    /// The request is not retried, so that the result reflects the current state of the
+    /// connection.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn ping(&self) -> Result<Duration, JornetError> {
+        http::ping(&self.http, "/health_check").await
+    }
+
+    /// Get the metadata of this leaderboard, like its name and number of scores, as configured
+    /// on the dashboard.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_leaderboard_info(&self) -> Result<LeaderboardInfo, JornetError> {
+        http::get(
+            &self.http,
+            &format!("/api/v1/leaderboards/{}/info", self.id),
+        )
+        .await
+    }
+
+    /// Get statistics about the scores of the leaderboard, computed by the server. If the server
+    /// doesn't support it, the leaderboard is downloaded to compute them.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_stats(&self) -> Result<LeaderboardStats, JornetError> {
+        match http::get(
+            &self.http,
+            &format!("/api/v1/leaderboards/{}/stats", self.id),
+        )
+        .await
+        {
+            Err(JornetError::HttpStatus(404)) => {
+                let scores = self.fetch_leaderboard().await?;
+                Ok(LeaderboardStats::from(&scores[..]))
+            }
+            result => result,
+        }
+    }
+
+    /// Get statistics about the scores of the current player in the leaderboard, like the
+    /// number of scores sent and the best one, computed by the server. If the server doesn't
+    /// support it, the leaderboard is downloaded to compute them.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_player_stats(&self) -> Result<PlayerStats, JornetError> {
+        let player = self
+            .player
+            .as_ref()
+            .ok_or(JornetError::NotConnectedAsPlayer)?;
+        match http::get(
+            &self.http,
+            &format!(
+                "/api/v1/leaderboards/{}/players/{}/stats",
+                self.id, player.id
+            ),
+        )
+        .await
+        {
+            Err(JornetError::HttpStatus(404)) => {
+                let mut scores = self.fetch_leaderboard().await?;
+                self.order.rank(&mut scores);
+                scores.retain(|score| score.player_id == Some(player.id));
+                Ok(PlayerStats::from_scores(&scores, self.order))
+            }
+            result => result,
+        }
+    }
+
+    /// Ask the server where a score would rank, and which fraction of the scores it's better
+    /// than, without downloading the leaderboard.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_percentile(&self, value: ScoreValue) -> Result<ScorePlacement, JornetError> {
+        let score = match value {
+            ScoreValue::Float(score) => score.to_string(),
+            ScoreValue::Integer(score) => score.to_string(),
+        };
+        http::get(
+            &self.http,
+            &format!(
+                "/api/v1/leaderboards/{}/placement{}",
+                self.id,
+                http::query_string(&[("score", score)])
+            ),
+        )
+        .await
+    }
+
+    /// Get the number of scores in the leaderboard, without downloading them. If the server
+    /// doesn't support it, the leaderboard is downloaded to count them.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_score_count(&self) -> Result<u64, JornetError> {
+        Ok(self.get_counts().await?.scores)
+    }
+
+    /// Get the number of players with a score in the leaderboard, without downloading the
+    /// scores. If the server doesn't support it, the leaderboard is downloaded to count them.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_player_count(&self) -> Result<u64, JornetError> {
+        Ok(self.get_counts().await?.players)
+    }
+
+    async fn get_counts(&self) -> Result<Counts, JornetError> {
+        match http::get(
+            &self.http,
+            &format!("/api/v1/leaderboards/{}/count", self.id),
+        )
+        .await
+        {
+            Err(JornetError::HttpStatus(404)) => {
+                let scores = self.fetch_leaderboard().await?;
+                // scores sent before players had IDs are counted by name
+                let players: HashSet<_> = scores
+                    .iter()
+                    .map(|score| {
+                        (
+                            score.player_id,
+                            score.player_id.is_none().then_some(&score.player),
+                        )
+                    })
+                    .collect();
+                Ok(Counts {
+                    scores: scores.len() as u64,
+                    players: players.len() as u64,
+                })
+            }
+            result => result,
+        }
+    }
+
+    /// Which scores are better in this leaderboard.
+    pub fn order(&self) -> ScoreOrder {
+        self.order
+    }
+
+    pub(crate) fn anonymizer(&self) -> Anonymizer {
+        Anonymizer {
+            names: self.player_names,
+            player: self.player.as_ref().map(|player| player.id),
+        }
+    }
+
+    /// Rank and sort scores of this leaderboard in a [`LeaderboardView`].
+    pub fn view(&self, scores: Vec<Score>) -> LeaderboardView {
+        LeaderboardView::new(scores, self.order)
+    }
+
+    /// Sort scores of this leaderboard, the best first.
+    pub fn sort_scores(&self, scores: &mut [Score]) {
+        self.order.sort(scores);
+    }
+
     /// Get the current player.
     ///
     /// This can be used to get the random name generated if one was not specified when
@@ -35,19 +374,496 @@ impl Leaderboard {
         self.player.as_ref()
     }
 
-    /// Create a player. If you don't specify a name, one will be generated randomly.
+    /// Create a player. If you don't specify a name, one will be generated randomly, by the
+    /// [name generator](LeaderboardBuilder::name_generator) if one is configured, otherwise by
+    /// the server.
     ///
     /// Either this or [`Self::as_player`] must be called before sending a score.
-    pub async fn create_player(&mut self, name: Option<&str>) -> anyhow::Result<&Player> {
-        let player = PlayerInput {
-            name: name.map(|n| n.to_string()),
+    ///
+    /// With the `itchio` feature, when the game is given the itch.io API key of the user, the
+    /// player of their itch.io account is connected instead, with the name of the account, so
+    /// that it's the same player in every session without saving it. See
+    /// [`Self::create_player_from_itchio`] for how the key is found.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn create_player(&mut self, name: Option<&str>) -> Result<&Player, JornetError> {
+        #[cfg(feature = "itchio")]
+        if let Some(api_key) = itchio::api_key() {
+            return self.create_player_from_itchio(&api_key).await;
+        }
+        let player = self.new_player(name).await?;
+        self.player_location = None;
+        Ok(self.player.insert(player))
+    }
+
+    /// Create a player like [`Self::create_player`], without connecting as it, for games that
+    /// keep the player themselves and send scores with [`Self::submit_as`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn new_player(&self, name: Option<&str>) -> Result<Player, JornetError> {
+        let mut player = PlayerInput {
+            name: name
+                .map(|n| n.to_string())
+                .or_else(|| self.name_generator.as_ref().map(NameGenerator::generate)),
+            unique: self.unique_names,
         };
-        if let Some(player) = http::post(&format!("{}/api/v1/players", self.host), player).await {
-            self.player = Some(player);
-            Ok(self.player.as_ref().unwrap())
-        } else {
-            anyhow::bail!("error creating a player");
+        if let (Some(filter), Some(name)) = (&self.name_filter, &player.name) {
+            filter.check(name)?;
+        }
+        if let (true, Some(generator)) = (self.unique_names && name.is_none(), &self.name_generator)
+        {
+            for _ in 1..GENERATED_NAME_ATTEMPTS {
+                match &player.name {
+                    Some(name) if !self.check_name_available(name).await? => {
+                        player.name = Some(generator.generate());
+                    }
+                    _ => break,
+                }
+            }
+        }
+        if let (true, Some(name)) = (self.unique_names, &player.name) {
+            if !self.check_name_available(name).await? {
+                return Err(JornetError::NameTaken(name.clone()));
+            }
+        }
+        let name = player.name.clone();
+        let result = match http::post_once(&self.http, "/api/v1/players", player).await {
+            // the name was taken between the check and the creation
+            Err(JornetError::HttpStatus(409)) if self.unique_names => {
+                Err(JornetError::NameTaken(name.unwrap_or_default()))
+            }
+            result => result,
+        };
+        self.report(Operation::CreatePlayer, result, |player| {
+            LeaderboardEvent::PlayerCreated(Player::clone(player))
+        })
+    }
+
+    /// Check whether no player has `name` yet, to tell the player before they confirm it. The
+    /// name of the current player is available to them. If the server doesn't support it, the
+    /// names of the players in the leaderboard are checked, ignoring case.
+    ///
+    /// The name can still be taken by another player before it's used, which
+    /// [`LeaderboardBuilder::unique_names`] reports with [`JornetError::NameTaken`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn check_name_available(&self, name: &str) -> Result<bool, JornetError> {
+        let player = self.player.as_ref().map(|player| player.id);
+        let path = format!(
+            "/api/v1/players/available{}",
+            http::query_string(&[("name", name.to_string())])
+        );
+        match http::get::<NameAvailability>(&self.http, &path).await {
+            Err(JornetError::HttpStatus(404)) => {
+                let name = name.to_lowercase();
+                let scores = self.fetch_leaderboard().await?;
+                Ok(!scores.iter().any(|score| {
+                    score.player.to_lowercase() == name
+                        && (score.player_id.is_none() || score.player_id != player)
+                }))
+            }
+            result => Ok(result?.available),
+        }
+    }
+
+    /// Create a player like [`Self::create_player`], and add it to the local players instead of
+    /// replacing the current player. See [`Self::add_local_player`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn create_local_player(
+        &mut self,
+        name: Option<&str>,
+    ) -> Result<&Player, JornetError> {
+        let player = self.new_player(name).await?;
+        self.add_local_player(player);
+        // the player was just added - can't fail
+        Ok(self.local_players.last().unwrap())
+    }
+
+    /// Connect another player on this device, like the second player of a couch co-op game,
+    /// besides the current player. Scores are sent as a local player with
+    /// [`ScoreSubmission::by_player`], while everything else is done as the current player.
+    ///
+    /// A local player with the same ID is replaced.
+    pub fn add_local_player(&mut self, player: Player) {
+        self.remove_local_player(player.id);
+        self.local_players.push(player);
+    }
+
+    /// Disconnect a local player added with [`Self::add_local_player`], and return it.
+    pub fn remove_local_player(&mut self, id: Uuid) -> Option<Player> {
+        let index = self
+            .local_players
+            .iter()
+            .position(|player| player.id == id)?;
+        Some(self.local_players.remove(index))
+    }
+
+    /// The players connected on this device: the current player if any, followed by the local
+    /// players in the order they were added.
+    pub fn local_players(&self) -> impl Iterator<Item = &Player> {
+        self.player.iter().chain(&self.local_players)
+    }
+
+    /// Get the public profile of a player, like the player of a score shown in the
+    /// leaderboard.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_player_profile(&self, id: Uuid) -> Result<PlayerProfile, JornetError> {
+        http::get(&self.http, &format!("/api/v1/players/{}", id)).await
+    }
+
+    /// Create a recovery code for the current player, that can be written down and typed on
+    /// another device to connect as the same player with [`Self::recover_player`], if the saved
+    /// player is lost. The request is signed with the key of the player.
+    ///
+    /// If the server doesn't support recovery codes, the ID and key of the player are encoded
+    /// in a longer code instead. Either way, anyone with the code can connect as the player, so
+    /// it should be kept secret.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn create_recovery_code(&self) -> Result<String, JornetError> {
+        let player = self
+            .player
+            .as_ref()
+            .ok_or(JornetError::NotConnectedAsPlayer)?;
+        match http::post_once::<_, RecoveryCode>(
+            &self.http,
+            &format!("/api/v1/players/{}/recovery", player.id),
+            RecoveryRequest::new(player),
+        )
+        .await
+        {
+            Err(JornetError::HttpStatus(404)) => Ok(recovery::encode(player)),
+            result => Ok(result?.code),
+        }
+    }
+
+    /// Connect as the player a recovery code was created for with
+    /// [`Self::create_recovery_code`], restoring their ID and key. Codes are read ignoring case,
+    /// dashes and spaces.
+    ///
+    /// The player isn't saved, see [`Player::save_to`] to keep it for the next sessions.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, code), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn recover_player(&mut self, code: &str) -> Result<&Player, JornetError> {
+        let player = match recovery::decode(code) {
+            // the code was encoded locally, only the name of the player is missing
+            Some((id, key)) => {
+                let profile = self.get_player_profile(id).await?;
+                Player {
+                    id,
+                    key,
+                    name: profile.name,
+                    avatar: profile.avatar,
+                    region: None,
+                }
+            }
+            None => {
+                http::post_once(
+                    &self.http,
+                    "/api/v1/players/recover",
+                    Recovery { code: code.trim() },
+                )
+                .await?
+            }
+        };
+        self.player_location = None;
+        Ok(self.player.insert(player))
+    }
+
+    /// Delete the current player and all their scores from the server, and forget the local
+    /// [`Player`]. Their scores waiting in the [offline queue](LeaderboardBuilder::offline_queue)
+    /// are removed too. The request is signed with the key of the player.
+    ///
+    /// The player is deleted from every leaderboard, and another player must be created or
+    /// connected to send scores after this.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn delete_player(&mut self) -> Result<(), JornetError> {
+        let player = self
+            .player
+            .as_ref()
+            .ok_or(JornetError::NotConnectedAsPlayer)?;
+        http::delete(
+            &self.http,
+            &format!("/api/v1/players/{}", player.id),
+            PlayerDeletion::new(player),
+        )
+        .await?;
+        if let Some(queue) = &self.queue {
+            queue.remove_player(player.id)?;
+        }
+        // cached leaderboards still have the scores of the player
+        *self.cache.lock().unwrap() = None;
+        *self.into_etag.lock().unwrap() = None;
+        self.player = None;
+        if let Some(location) = self.player_location.take() {
+            self.credential_protection.remove(&location)?;
+        }
+        Ok(())
+    }
+
+    /// Connect as the player saved at `location`, or create a player named `name` and save it
+    /// there if there is none, so that the game keeps the same player across sessions. See
+    /// [`Player::save_to`] for what `location` is on each platform. The player is protected as
+    /// configured with [`LeaderboardBuilder::credential_protection`].
+    ///
+    /// If the saved player can't be read, this fails instead of replacing it with a new player.
+    /// If a new player can't be saved, this fails but the player is still connected. The saved
+    /// player is kept up to date by [`Self::rename_player`], and removed by
+    /// [`Self::delete_player`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn connect_or_create(
+        &mut self,
+        location: &str,
+        name: Option<&str>,
+    ) -> Result<&Player, JornetError> {
+        let protection = self.credential_protection.clone();
+        if let Some(player) = Player::load_protected(location, &protection)? {
+            self.player_location = Some(location.to_string());
+            return Ok(self.player.insert(player));
+        }
+        self.create_player(name)
+            .await?
+            .save_protected(location, &protection)?;
+        self.player_location = Some(location.to_string());
+        // the player was just created - can't fail
+        Ok(self.player.as_ref().unwrap())
+    }
+
+    /// Link the current player to an external account, like a guest player logging in, so that
+    /// they keep their scores and can connect as the same player with the account later, with
+    /// [`Self::connect_with_account`]. `provider` identifies the service of the account, like
+    /// `steam` or `discord`, and `token` proves the account belongs to the player.
+    ///
+    /// The request is signed with the key of the player. The server may update the player, for
+    /// example with the name of the account, and the saved player is updated if it was connected
+    /// with [`Self::connect_or_create`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, token), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn link_player(
+        &mut self,
+        provider: &str,
+        token: &str,
+    ) -> Result<&Player, JornetError> {
+        let player = self
+            .player
+            .as_ref()
+            .ok_or(JornetError::NotConnectedAsPlayer)?;
+        let linked: Player = http::post_once(
+            &self.http,
+            &format!("/api/v1/players/{}/accounts", player.id),
+            AccountLink::new(provider, token, player),
+        )
+        .await?;
+        if let Some(location) = &self.player_location {
+            linked.save_protected(location, &self.credential_protection)?;
+        }
+        Ok(self.player.insert(linked))
+    }
+
+    /// Connect as the player linked to an external account with [`Self::link_player`], or
+    /// create a player for the account if there is none. See [`Self::link_player`] for
+    /// `provider` and `token`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, token), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn connect_with_account(
+        &mut self,
+        provider: &str,
+        token: &str,
+    ) -> Result<&Player, JornetError> {
+        let player = self.report(
+            Operation::CreatePlayer,
+            http::post_once(
+                &self.http,
+                "/api/v1/players/accounts",
+                AccountLogin {
+                    provider: provider.to_string(),
+                    token: token.to_string(),
+                },
+            )
+            .await,
+            |player| LeaderboardEvent::PlayerCreated(Player::clone(player)),
+        )?;
+        self.player_location = None;
+        Ok(self.player.insert(player))
+    }
+
+    /// Connect as the player of a Steam account, created the first time with the persona name
+    /// of the account. `ticket` is an authentication session ticket for the Web API, as given by
+    /// `ISteamUser::GetAuthTicketForWebApi`, checked by the server.
+    ///
+    /// A guest player can be linked to a Steam account with [`Self::link_player`], using the
+    /// `steam` provider and the ticket encoded in hexadecimal.
+    #[cfg(feature = "steam")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, ticket), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn create_player_from_steam(
+        &mut self,
+        ticket: &[u8],
+    ) -> Result<&Player, JornetError> {
+        self.connect_with_account("steam", &hex::encode(ticket))
+            .await
+    }
+
+    /// Connect as the player of a Discord user, created the first time with the username and
+    /// avatar of the user. `access_token` is an OAuth2 access token with the `identify` scope,
+    /// like the one obtained by a Discord Activity with the Embedded App SDK, checked by the
+    /// server.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, access_token), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn create_player_from_discord(
+        &mut self,
+        access_token: &str,
+    ) -> Result<&Player, JornetError> {
+        self.connect_with_account("discord", access_token).await
+    }
+
+    /// Connect as the player of an itch.io account, created the first time with the name of the
+    /// account. `api_key` is the API key of the user given to the game, checked by the server.
+    ///
+    /// [`Self::create_player`] does this automatically with the `itchio` feature, when the key
+    /// is found in the `ITCHIO_API_KEY` environment variable, set by the itch.io app for the
+    /// games it launches, or on wasm in the `api_key` query parameter of the page of the game,
+    /// set by the page embedding it.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, api_key), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn create_player_from_itchio(
+        &mut self,
+        api_key: &str,
+    ) -> Result<&Player, JornetError> {
+        self.connect_with_account("itchio", api_key).await
+    }
+
+    /// Rename the current player to the persona name of their Steam account if it changed, so
+    /// that the leaderboard shows the same name as Steam. This can be called on startup, and
+    /// when Steam reports a persona change.
+    #[cfg(feature = "steam")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn sync_steam_persona_name(
+        &mut self,
+        persona_name: &str,
+    ) -> Result<&Player, JornetError> {
+        if !matches!(&self.player, Some(player) if player.name == persona_name) {
+            return self.rename_player(persona_name).await;
         }
+        // the player was just checked - can't fail
+        Ok(self.player.as_ref().unwrap())
+    }
+
+    /// Change the name of the current player on the server, and in the local [`Player`]. The
+    /// request is signed with the key of the player.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn rename_player(&mut self, new_name: &str) -> Result<&Player, JornetError> {
+        if self.player.is_none() {
+            return Err(JornetError::NotConnectedAsPlayer);
+        }
+        if let Some(filter) = &self.name_filter {
+            filter.check(new_name)?;
+        }
+        let taken = || JornetError::NameTaken(new_name.to_string());
+        if self.unique_names && !self.check_name_available(new_name).await? {
+            return Err(taken());
+        }
+        let player = self
+            .player
+            .as_mut()
+            .ok_or(JornetError::NotConnectedAsPlayer)?;
+        match http::put(
+            &self.http,
+            &format!("/api/v1/players/{}", player.id),
+            PlayerRename::new(new_name, player),
+        )
+        .await
+        {
+            Err(JornetError::HttpStatus(409)) if self.unique_names => return Err(taken()),
+            result => result?,
+        }
+        player.name = new_name.to_string();
+        if let Some(location) = &self.player_location {
+            player.save_protected(location, &self.credential_protection)?;
+        }
+        Ok(player)
+    }
+
+    /// Set the country or region of the current player, sent with their following scores so
+    /// that they can be shown with a flag and filtered with [`ScoreQuery::region`]. `region` is
+    /// an ISO 3166-1 alpha-2 code like `FR`, or `None` to stop sending one. The region is kept in
+    /// the saved player if it was connected with [`Self::connect_or_create`].
+    ///
+    /// See [`Self::lookup_region`] to find the region of the player from their connection.
+    pub fn set_player_region(&mut self, region: Option<&str>) -> Result<(), JornetError> {
+        let player = self
+            .player
+            .as_mut()
+            .ok_or(JornetError::NotConnectedAsPlayer)?;
+        let region = region
+            .map(|region| {
+                match region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic()) {
+                    true => Ok(region.to_ascii_uppercase()),
+                    false => Err(JornetError::InvalidConfiguration(format!(
+                        "invalid region code: {}",
+                        region
+                    ))),
+                }
+            })
+            .transpose()?;
+        player.region = region;
+        if let Some(location) = &self.player_location {
+            player.save_protected(location, &self.credential_protection)?;
+        }
+        Ok(())
+    }
+
+    /// Ask the server for the country or region of the player, from the IP address of the
+    /// request. Returns `None` if the server doesn't know it. The result can be passed to
+    /// [`Self::set_player_region`].
+    ///
+    /// This reveals the approximate location of the player, so it should only be called once
+    /// they agreed to it.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn lookup_region(&self) -> Result<Option<String>, JornetError> {
+        let lookup: RegionLookup = http::get(&self.http, "/api/v1/geo").await?;
+        Ok(lookup.region)
     }
 
     /// Connect as a returning player.
@@ -55,85 +871,1166 @@ impl Leaderboard {
     /// Either this or [`Self::create_player`] must be called before sending a score.
     pub fn as_player(&mut self, player: Player) {
         self.player = Some(player);
+        self.player_location = None;
     }
 
     /// Send a score to the leaderboard.
-    pub async fn send_score(&self, score: f32) -> Option<()> {
-        self.inner_send_score_with_meta(score, None).await
+    ///
+    /// Returns what the server reported about the submission, like the rank of the score.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn send_score(&self, score: f32) -> Result<SubmittedScore, JornetError> {
+        self.inner_submit(ScoreSubmission::new(score)).await
+    }
+
+    /// Send an integer score to the leaderboard.
+    ///
+    /// Unlike `f32` scores, integer scores don't lose precision above 2^24.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn send_score_i64(&self, score: i64) -> Result<SubmittedScore, JornetError> {
+        self.inner_submit(ScoreSubmission::integer(score)).await
+    }
+
+    /// Send a duration to the leaderboard, like a completion time. It's sent as an integer
+    /// number of milliseconds, and can be read back with [`Score::as_duration`].
+    ///
+    /// Leaderboards of durations are usually built with [`ScoreOrder::LowerIsBetter`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn send_duration(&self, duration: Duration) -> Result<SubmittedScore, JornetError> {
+        self.inner_submit(ScoreSubmission::duration(duration)).await
     }
 
     /// Send a score with metadata to the leaderboard.
     ///
     /// Metadata can be information about the game, victory conditions, ...
-    pub async fn send_score_with_meta(&self, score: f32, meta: &str) -> Option<()> {
-        self.inner_send_score_with_meta(score, Some(meta.to_string()))
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn send_score_with_meta(
+        &self,
+        score: f32,
+        meta: &str,
+    ) -> Result<SubmittedScore, JornetError> {
+        self.inner_submit(ScoreSubmission::new(score).meta(meta))
             .await
     }
 
-    async fn inner_send_score_with_meta(&self, score: f32, meta: Option<String>) -> Option<()> {
-        let leaderboard_id = self.id;
-        let host = self.host.clone();
+    /// Send a score with typed metadata to the leaderboard. The metadata is serialized to JSON,
+    /// and can be read back with [`Score::meta_as`]. See [`ScoreSubmission::meta_typed`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, meta), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn send_score_with_meta_typed(
+        &self,
+        score: f32,
+        meta: impl Serialize,
+    ) -> Result<SubmittedScore, JornetError> {
+        self.inner_submit(ScoreSubmission::new(score).meta_typed(meta)?)
+            .await
+    }
 
-        if let Some(player) = self.player.as_ref() {
-            let score_to_send = ScoreInput::new(self.key, score, player, meta);
-            if http::post::<_, ()>(
-                &format!("{}/api/v1/scores/{}", host, leaderboard_id),
-                score_to_send,
-            )
+    /// Send a score with named secondary values, like the number of laps or the top speed in a
+    /// race. They are signed with the score, and can be read back with [`Score::dimension`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn send_score_with_dimensions(
+        &self,
+        score: f32,
+        dimensions: &[(&str, f64)],
+    ) -> Result<SubmittedScore, JornetError> {
+        let submission = dimensions
+            .iter()
+            .fold(ScoreSubmission::new(score), |submission, (name, value)| {
+                submission.dimension(name, *value)
+            });
+        self.inner_submit(submission).await
+    }
+
+    /// Send an integer score with metadata to the leaderboard.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn send_score_i64_with_meta(
+        &self,
+        score: i64,
+        meta: &str,
+    ) -> Result<SubmittedScore, JornetError> {
+        self.inner_submit(ScoreSubmission::integer(score).meta(meta))
+            .await
+    }
+
+    /// Send a score to a sub-board of the leaderboard, like `level-42`. Groups share the
+    /// credentials of the leaderboard, so that games with many levels don't need to create a
+    /// leaderboard for each one.
+    ///
+    /// Scores of a group can be fetched with [`Self::get_leaderboard_for`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn send_score_for(
+        &self,
+        group: &str,
+        score: f32,
+    ) -> Result<SubmittedScore, JornetError> {
+        self.inner_submit(ScoreSubmission::new(score).group(group))
+            .await
+    }
+
+    /// Send a score only if it beats the best score of the current player in the leaderboard,
+    /// according to the [order](LeaderboardBuilder::order) of the leaderboard,
+    /// so that the leaderboard keeps a single score per player when it's the only way scores are
+    /// sent.
+    ///
+    /// Returns `None` if the score was not sent. Scores of the player are found by their ID if
+    /// the server exposes it, otherwise by their name.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn send_score_if_best(
+        &self,
+        score: f32,
+    ) -> Result<Option<SubmittedScore>, JornetError> {
+        let player = self
+            .player
+            .as_ref()
+            .ok_or(JornetError::NotConnectedAsPlayer)?;
+        // only the best score of the player is needed, without refreshing the leaderboard
+        let best = self
+            .inner_query(ScoreQuery::new().player(player).top(1))
+            .await?;
+        let beaten = best.iter().any(|existing| {
+            !self
+                .order
+                .is_better(ScoreValue::Float(score), existing.value())
+        });
+        if beaten {
+            return Ok(None);
+        }
+        self.inner_submit(ScoreSubmission::new(score))
             .await
-            .is_none()
-            {
-                return None; // TODO warn!("error sending the score");
+            .map(Some)
+    }
+
+    /// Send a score with everything that can be sent along with it, like its metadata or when
+    /// it was achieved.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn submit(&self, submission: ScoreSubmission) -> Result<SubmittedScore, JornetError> {
+        self.inner_submit(submission).await
+    }
+
+    /// Send a score like [`Self::submit`], as `player` instead of a connected player. This lets
+    /// requests run concurrently while the player is kept elsewhere, like in a game engine.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, player), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn submit_as(
+        &self,
+        player: &Player,
+        submission: ScoreSubmission,
+    ) -> Result<SubmittedScore, JornetError> {
+        let result = self.send_submission_as(player, submission).await;
+        self.report(Operation::SubmitScore, result, |submitted| {
+            LeaderboardEvent::ScoreSubmitted(submitted.clone())
+        })
+    }
+
+    /// Send several scores to the leaderboard, with optional metadata. Scores are sent
+    /// concurrently, which is faster than sending them one after the other.
+    ///
+    /// Returns the result of each submission, in the same order as `scores`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id))
+    )]
+    pub async fn send_scores(
+        &self,
+        scores: &[(f32, Option<&str>)],
+    ) -> Vec<Result<SubmittedScore, JornetError>> {
+        join::join_limited(
+            scores
+                .iter()
+                .map(|(score, meta)| {
+                    let submission = ScoreSubmission::new(*score);
+                    self.inner_submit(match meta {
+                        Some(meta) => submission.meta(meta),
+                        None => submission,
+                    })
+                })
+                .collect(),
+            MAX_CONCURRENT_SUBMISSIONS,
+        )
+        .await
+    }
+
+    /// Send the scores kept in the offline queue, in the order they were submitted. This is done
+    /// automatically before sending a new score.
+    ///
+    /// Returns how many scores were sent. If the server can't be reached, the remaining scores
+    /// are kept and the error is returned. Scores rejected by the server are discarded.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn send_queued_scores(&self) -> Result<usize, JornetError> {
+        let Some(queue) = &self.queue else {
+            return Ok(0);
+        };
+        let Some(_flush) = queue.flush() else {
+            // another submission is already sending them
+            return Ok(0);
+        };
+        let mut sent = 0;
+        // each score stays in the queue until the server answered it, so that none is lost if
+        // the game stops while sending them
+        while let Some(score) = queue.first()? {
+            match self.post_score(&score).await {
+                Err(error) if OfflineQueue::should_queue(&error) => return Err(error),
+                Ok(_) => sent += 1,
+                Err(_error) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(error = %_error, "discarding queued score rejected by the server");
+                }
+            }
+            queue.remove(&score)?;
+        }
+        Ok(sent)
+    }
+
+    async fn inner_submit(
+        &self,
+        submission: ScoreSubmission,
+    ) -> Result<SubmittedScore, JornetError> {
+        let result = self.send_submission(submission).await;
+        self.report(Operation::SubmitScore, result, |submitted| {
+            LeaderboardEvent::ScoreSubmitted(submitted.clone())
+        })
+    }
+
+    async fn send_submission(
+        &self,
+        submission: ScoreSubmission,
+    ) -> Result<SubmittedScore, JornetError> {
+        let player = match submission.player {
+            Some(id) => self.local_players().find(|player| player.id == id),
+            None => self.player.as_ref(),
+        }
+        .ok_or(JornetError::NotConnectedAsPlayer)?;
+        self.send_submission_as(player, submission).await
+    }
+
+    async fn send_submission_as(
+        &self,
+        player: &Player,
+        submission: ScoreSubmission,
+    ) -> Result<SubmittedScore, JornetError> {
+        for validator in &self.validators {
+            validator(&submission).map_err(JornetError::InvalidScore)?;
+        }
+        // the key is generated once, so that retries of the request share it
+        let idempotency_key = submission
+            .idempotency_key
+            .or_else(|| self.idempotency_keys.then(Uuid::new_v4));
+        let nonce = self.nonces.then(Uuid::new_v4);
+        let sign = |session: Option<&Session>| {
+            ScoreInput::new(
+                self.key,
+                player,
+                session,
+                submission.clone(),
+                idempotency_key,
+                nonce,
+            )
+        };
+        let Some(queue) = &self.queue else {
+            return self.post_signed_score(player, sign).await;
+        };
+        // scores are kept in order: if the queue can't be sent, the server is unreachable
+        let result = match self.send_queued_scores().await {
+            Ok(_) => self.post_signed_score(player, sign).await,
+            Err(error) => Err(error),
+        };
+        match result {
+            Err(error) if OfflineQueue::should_queue(&error) => {
+                // sessions can expire before the queue is sent
+                queue.push(sign(None))?;
+                Ok(SubmittedScore {
+                    queued: true,
+                    ..Default::default()
+                })
+            }
+            result => result,
+        }
+    }
+
+    /// Sign a score with a session of the player if session tokens are enabled, and send it.
+    /// If the server rejects the session, a new one is started and the score is sent again.
+    async fn post_signed_score(
+        &self,
+        player: &Player,
+        sign: impl Fn(Option<&Session>) -> ScoreInput,
+    ) -> Result<SubmittedScore, JornetError> {
+        if !self.session_tokens {
+            return self.post_score(&sign(None)).await;
+        }
+        let session = self.session(player).await?;
+        match self.post_score(&sign(session.as_ref())).await {
+            Err(JornetError::HttpStatus(401)) if session.is_some() => {
+                self.sessions.lock().unwrap().remove(&player.id);
+                let session = self.session(player).await?;
+                self.post_score(&sign(session.as_ref())).await
+            }
+            result => result,
+        }
+    }
+
+    /// Get the session of a player, starting a new one if it's missing or expiring. Returns
+    /// `None` if the server doesn't support sessions, in which case scores are signed with the
+    /// key of the player.
+    async fn session(&self, player: &Player) -> Result<Option<Session>, JornetError> {
+        let current = self.sessions.lock().unwrap().get(&player.id).cloned();
+        if let Some(session) = current.filter(|session| !session.is_expiring()) {
+            return Ok(Some(session));
+        }
+        let path = format!("/api/v1/players/{}/sessions", player.id);
+        // concurrent submissions share the same session
+        let session = self
+            .session_requests
+            .run(&path, || {
+                http::post(&self.http, &path, SessionRequest::new(player))
+            })
+            .await;
+        match session {
+            Ok(session) => {
+                self.sessions
+                    .lock()
+                    .unwrap()
+                    .insert(player.id, Session::clone(&session));
+                Ok(Some(session))
             }
-            Some(())
+            Err(JornetError::HttpStatus(404)) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Start a session for the current player, so that the key of the player isn't used again
+    /// to sign scores until the session expires. Sessions are otherwise started when the first
+    /// score is sent, and renewed when they expire. See
+    /// [`LeaderboardBuilder::session_tokens`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn start_session(&self) -> Result<(), JornetError> {
+        let player = self
+            .player
+            .as_ref()
+            .ok_or(JornetError::NotConnectedAsPlayer)?;
+        self.session(player).await.map(|_| ())
+    }
+
+    async fn post_score(&self, score: &ScoreInput) -> Result<SubmittedScore, JornetError> {
+        let http = self.http_for(Operation::SubmitScore);
+        let path = format!("/api/v1/scores/{}", self.id);
+        // a score is only sent again if the server can tell it apart from a new one, otherwise
+        // an attempt that was saved but whose response was lost would fail the retries
+        let result = if score.idempotency_key.is_some() || score.nonce.is_some() {
+            http::post(&http, &path, score).await
         } else {
-            None
+            http::post_once(&http, &path, score).await
+        };
+        // servers that don't report on the submission answer with `null`
+        let submitted: Option<SubmittedScore> = match result {
+            // the nonce was already used: a previous attempt of this request reached the
+            // server, or the request was replayed
+            Err(JornetError::HttpStatus(409)) if score.nonce.is_some() => Some(SubmittedScore {
+                replayed: true,
+                ..Default::default()
+            }),
+            result => result?,
+        };
+        Ok(submitted.unwrap_or_default())
+    }
+
+    /// Delete a score sent by the current player. The ID of a score is available in
+    /// [`SubmittedScore::id`] or [`Score::id`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn delete_score(&self, score_id: Uuid) -> Result<(), JornetError> {
+        let player = self
+            .player
+            .as_ref()
+            .ok_or(JornetError::NotConnectedAsPlayer)?;
+        http::delete(
+            &self.http,
+            &format!("/api/v1/scores/{}/{}", self.id, score_id),
+            ScoreDeletion::new(self.key, score_id, player),
+        )
+        .await
+    }
+
+    /// Send a score with an attachment, like a replay that other players can download with
+    /// [`Self::download_attachment`].
+    ///
+    /// The server must return the ID of the score to upload the attachment. If the score was
+    /// kept in the offline queue, the attachment is not uploaded.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, attachment), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn send_score_with_attachment(
+        &self,
+        score: f32,
+        attachment: &[u8],
+    ) -> Result<SubmittedScore, JornetError> {
+        let submitted = self.inner_submit(ScoreSubmission::new(score)).await?;
+        if submitted.queued {
+            return Ok(submitted);
+        }
+        let score_id = submitted.id.ok_or_else(|| {
+            JornetError::Deserialization("the server didn't return the ID of the score".to_string())
+        })?;
+        self.upload_attachment(score_id, attachment).await?;
+        Ok(submitted)
+    }
+
+    /// Attach data to a score sent by the current player, replacing any previous attachment.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, attachment), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn upload_attachment(
+        &self,
+        score_id: Uuid,
+        attachment: &[u8],
+    ) -> Result<(), JornetError> {
+        let player = self
+            .player
+            .as_ref()
+            .ok_or(JornetError::NotConnectedAsPlayer)?;
+        let timestamp = now_secs();
+        let mut mac = Hmac::<Sha256>::new_from_slice(player.key.as_bytes()).unwrap();
+        mac.update(&timestamp.to_le_bytes());
+        mac.update(self.key.as_bytes());
+        mac.update(player.id.as_bytes());
+        mac.update(score_id.as_bytes());
+        mac.update(&Sha256::digest(attachment));
+        let hmac = hex::encode(&mac.finalize().into_bytes()[..]);
+
+        let player_id = player.id.to_string();
+        let timestamp = timestamp.to_string();
+        http::put_bytes(
+            &self.http,
+            &format!("/api/v1/scores/{}/{}/attachment", self.id, score_id),
+            &[
+                ("Content-Type", "application/octet-stream"),
+                ("X-Jornet-Player", &player_id),
+                ("X-Jornet-Timestamp", &timestamp),
+                ("X-Jornet-Signature", &hmac),
+            ],
+            attachment,
+        )
+        .await
+    }
+
+    /// Download the attachment of a score. Returns `None` if the score has no attachment.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, score), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn download_attachment(&self, score: &Score) -> Result<Option<Vec<u8>>, JornetError> {
+        match score.attachment_url.as_deref() {
+            Some(url) => http::get_bytes(&self.http, url).await.map(Some),
+            None => Ok(None),
         }
     }
 
     /// Get the leaderboard data.
-    pub async fn get_leaderboard(&self) -> anyhow::Result<Vec<Score>> {
-        if let Some(scores) = http::get(&format!("{}/api/v1/scores/{}", self.host, self.id)).await {
-            Ok(scores)
-        } else {
-            anyhow::bail!("error getting the leaderboard")
+    ///
+    /// If the server supports it, the leaderboard is only downloaded again if it changed since
+    /// the last call.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_leaderboard(&self) -> Result<Vec<Score>, JornetError> {
+        let result = self.fetch_leaderboard().await.map(|mut scores| {
+            self.order.rank(&mut scores);
+            scores
+        });
+        if let (Some(snapshot), Ok(scores)) = (&self.snapshot, &result) {
+            if let Err(_error) = snapshot.save(scores) {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %_error, "failed to save the leaderboard snapshot");
+            }
+        }
+        let result = result.map(|mut scores| {
+            self.anonymizer().apply(&mut scores);
+            scores
+        });
+        self.report(Operation::RefreshLeaderboard, result, |scores| {
+            LeaderboardEvent::LeaderboardRefreshed(scores.clone())
+        })
+    }
+
+    async fn fetch_leaderboard(&self) -> Result<Vec<Score>, JornetError> {
+        let path = format!("/api/v1/scores/{}", self.id);
+        // concurrent calls share the same request
+        self.requests
+            .run(&path, || async {
+                let http = self.http_for(Operation::RefreshLeaderboard);
+                let etag = self
+                    .cache
+                    .lock()
+                    .unwrap()
+                    .as_ref()
+                    .map(|cached| cached.etag.clone());
+                match http::get_if_none_match(&http, &path, etag.as_deref()).await? {
+                    Conditional::Modified::<Vec<Score>> { value, etag } => {
+                        *self.cache.lock().unwrap() = etag.map(|etag| CachedLeaderboard {
+                            etag,
+                            scores: value.clone(),
+                        });
+                        Ok(value)
+                    }
+                    Conditional::NotModified => {
+                        let cached = self
+                            .cache
+                            .lock()
+                            .unwrap()
+                            .as_ref()
+                            .map(|cached| cached.scores.clone());
+                        match cached {
+                            Some(scores) => Ok(scores),
+                            None => http::get(&http, &path).await,
+                        }
+                    }
+                }
+            })
+            .await
+    }
+
+    /// Get the leaderboard saved by the last successful [`Self::get_leaderboard`], without
+    /// sending a request, so that it can be shown as soon as the game starts. Returns `None` if
+    /// no snapshot cache is configured, or if the snapshot is older than its TTL. See
+    /// [`LeaderboardBuilder::snapshot_cache`].
+    pub fn cached_leaderboard(&self) -> Option<Vec<Score>> {
+        let mut scores = self.snapshot.as_ref()?.load()?;
+        self.anonymizer().apply(&mut scores);
+        Some(scores)
+    }
+
+    /// Refresh the leaderboard in the background every `interval`, until the returned handle is
+    /// dropped. The handle gives the latest leaderboard received, and can notify when it
+    /// changes.
+    ///
+    /// Refreshes are reported to the [listener](LeaderboardBuilder::on_event), if any.
+    pub fn start_auto_refresh(&self, interval: Duration) -> AutoRefresh {
+        AutoRefresh::start(
+            self.http_for(Operation::RefreshLeaderboard).into_owned(),
+            format!("/api/v1/scores/{}", self.id),
+            self.order,
+            self.anonymizer(),
+            self.listener.clone(),
+            interval,
+        )
+    }
+
+    /// Get the leaderboard data into `scores`, reusing the allocations of the scores it
+    /// already contains, including the strings of player names. This is useful to refresh the
+    /// leaderboard often without allocating each time.
+    ///
+    /// `scores` should be the vector filled by the previous call: if the server reports that
+    /// the leaderboard didn't change since, it's left untouched.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, scores), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_leaderboard_into(&self, scores: &mut Vec<Score>) -> Result<(), JornetError> {
+        let result = self.fetch_leaderboard_into(scores).await;
+        self.report(Operation::RefreshLeaderboard, result, |_| {
+            LeaderboardEvent::LeaderboardRefreshed(scores.clone())
+        })
+    }
+
+    async fn fetch_leaderboard_into(&self, scores: &mut Vec<Score>) -> Result<(), JornetError> {
+        let http = self.http_for(Operation::RefreshLeaderboard);
+        let path = format!("/api/v1/scores/{}", self.id);
+        // an empty vector can't hold the unchanged leaderboard
+        let etag = match scores.is_empty() {
+            true => None,
+            false => self.into_etag.lock().unwrap().clone(),
+        };
+        let (response, etag) =
+            match http::get_response_if_none_match(&http, &path, etag.as_deref()).await? {
+                Conditional::Modified { value, etag } => (value, etag),
+                Conditional::NotModified => return Ok(()),
+            };
+        http::parse_in_place(&response, scores)?;
+        self.order.rank(scores);
+        self.anonymizer().apply(scores);
+        *self.into_etag.lock().unwrap() = etag;
+        Ok(())
+    }
+
+    /// Get the leaderboard data, parsing scores only as they are iterated over. This is useful
+    /// for large leaderboards, to parse them over several frames.
+    ///
+    /// Scores are in the order sent by the server, without rank.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_leaderboard_iter(&self) -> Result<ScoreIter, JornetError> {
+        let body = http::get_bytes(&self.http, &format!("/api/v1/scores/{}", self.id)).await?;
+        ScoreIter::new(body, self.anonymizer())
+    }
+
+    /// Get the scores of the leaderboard from the best, as a [`Stream`](futures_core::Stream)
+    /// that requests them page by page as it's polled, until the last one. This is useful to
+    /// go through a large leaderboard without downloading it at once.
+    ///
+    /// If the server doesn't support pagination, the whole leaderboard is downloaded with the
+    /// first page.
+    pub fn scores_stream(&self) -> ScorePages<'_> {
+        ScorePages::new(self)
+    }
+
+    /// Receive the scores sent to the leaderboard as they arrive, through server-sent events.
+    /// This works with hosting setups that block websockets.
+    ///
+    /// On wasm, the stream is opened by the browser and doesn't send the headers configured on
+    /// the leaderboard.
+    pub fn stream_scores(&self) -> ScoreStream {
+        ScoreStream::new(
+            &self.http,
+            &format!("/api/v1/scores/{}/events", self.id),
+            self.anonymizer(),
+        )
+    }
+
+    /// Get the best `n` scores of the leaderboard, sorted from the best. Only those scores are
+    /// downloaded if the server supports it.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_top(&self, n: usize) -> Result<Vec<Score>, JornetError> {
+        self.inner_query(ScoreQuery::new().top(n)).await
+    }
+
+    /// Get the best score of the current player, with its rank in the leaderboard. Returns
+    /// `None` if the player has no score in this leaderboard.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_personal_best(&self) -> Result<Option<Score>, JornetError> {
+        let player = self
+            .player
+            .as_ref()
+            .ok_or(JornetError::NotConnectedAsPlayer)?;
+        let mut scores = self
+            .inner_query(ScoreQuery::new().player(player).top(1))
+            .await?;
+        Ok(scores.pop())
+    }
+
+    /// Get all the scores of the current player, from the oldest to the most recent.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_player_scores(&self) -> Result<Vec<Score>, JornetError> {
+        let player = self
+            .player
+            .as_ref()
+            .ok_or(JornetError::NotConnectedAsPlayer)?;
+        let mut scores = self.inner_query(ScoreQuery::new().player(player)).await?;
+        scores.sort_by_key(|score| score.unix_timestamp());
+        Ok(scores)
+    }
+
+    /// Get the scores achieved since `timestamp`, in seconds since the Unix epoch, from the
+    /// oldest to the most recent. Scores achieved during the second of `timestamp` are included,
+    /// they can be merged with the scores already received with [`Self::merge_scores`].
+    ///
+    /// Scores don't have a rank, as only part of the leaderboard is received.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_scores_since(&self, timestamp: u64) -> Result<Vec<Score>, JornetError> {
+        let query = ScoreQuery::new().window(TimeWindow::Since(timestamp));
+        let mut scores: Vec<Score> = http::get(&self.http, &query.path(self.id)).await?;
+        query.filter_window(&mut scores);
+        for score in &mut scores {
+            score.rank = None;
+        }
+        scores.sort_by_key(|score| score.unix_timestamp());
+        Ok(scores)
+    }
+
+    /// Add new scores to scores already received, ignoring those already present. Scores are
+    /// then ranked and sorted from the best.
+    pub fn merge_scores(&self, scores: &mut Vec<Score>, new_scores: Vec<Score>) {
+        for score in new_scores {
+            if !scores.iter().any(|existing| existing.is_same(&score)) {
+                scores.push(score);
+            }
+        }
+        for score in scores.iter_mut() {
+            score.rank = None;
+        }
+        self.order.rank(scores);
+        self.order.sort(scores);
+    }
+
+    /// Get the scores of the players with the given IDs, like the friends of the current player,
+    /// sorted from the best. Ranks are those of the scores in the whole leaderboard. See
+    /// [`ScoreQuery::players`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, ids), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_leaderboard_for_players(
+        &self,
+        ids: &[Uuid],
+    ) -> Result<Vec<Score>, JornetError> {
+        // without players, the query would not be filtered
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+        self.inner_query(ScoreQuery::new().players(ids)).await
+    }
+
+    /// Get the leaderboard of teams, combining the best score of each of their members with
+    /// `aggregation`, sorted from the best. Scores are sent with a team with
+    /// [`ScoreSubmission::team`].
+    ///
+    /// If the server doesn't support team leaderboards, the leaderboard is downloaded to
+    /// combine scores.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_team_leaderboard(
+        &self,
+        aggregation: Aggregation,
+    ) -> Result<Vec<TeamScore>, JornetError> {
+        let path = format!(
+            "/api/v1/scores/{}/teams{}",
+            self.id,
+            http::query_string(&[("aggregation", aggregation.name().to_string())])
+        );
+        match http::get(&self.http, &path).await {
+            Err(JornetError::HttpStatus(404)) => {
+                let scores = self.fetch_leaderboard().await?;
+                Ok(aggregation.aggregate(scores, self.order))
+            }
+            result => result,
+        }
+    }
+
+    /// Get the scores of players whose name starts with `prefix`, ignoring case, sorted from
+    /// the best.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn search_scores(&self, prefix: &str) -> Result<Vec<Score>, JornetError> {
+        self.inner_query(ScoreQuery::new().search(prefix)).await
+    }
+
+    /// Get the season currently running in the leaderboard, or `None` if the leaderboard
+    /// doesn't have seasons.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_current_season(&self) -> Result<Option<Season>, JornetError> {
+        http::get(
+            &self.http,
+            &format!("/api/v1/leaderboards/{}/seasons/current", self.id),
+        )
+        .await
+    }
+
+    /// Get all the seasons of the leaderboard, past and current.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_seasons(&self) -> Result<Vec<Season>, JornetError> {
+        http::get(
+            &self.http,
+            &format!("/api/v1/leaderboards/{}/seasons", self.id),
+        )
+        .await
+    }
+
+    /// Send a score tagged with a season. Scores are usually sent in the current season, see
+    /// [`Self::get_current_season`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, season), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn send_score_in_season(
+        &self,
+        season: &Season,
+        score: f32,
+    ) -> Result<SubmittedScore, JornetError> {
+        self.inner_submit(ScoreSubmission::new(score).season(&season.id))
+            .await
+    }
+
+    /// Get the scores of a season, ranked within it and sorted from the best.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, season), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_season_leaderboard(&self, season: &Season) -> Result<Vec<Score>, JornetError> {
+        self.inner_query(ScoreQuery::new().season(&season.id)).await
+    }
+
+    /// Get the scores of a sub-board of the leaderboard, ranked within it and sorted from the
+    /// best. See [`Self::send_score_for`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn get_leaderboard_for(&self, group: &str) -> Result<Vec<Score>, JornetError> {
+        self.inner_query(ScoreQuery::new().group(group)).await
+    }
+
+    /// Get the scores matching a query, sorted from the best.
+    ///
+    /// ```no_run
+    /// # async fn run(leaderboard: &jornet::Leaderboard) -> Result<(), jornet::JornetError> {
+    /// use jornet::{ScoreQuery, TimeWindow};
+    ///
+    /// let best_today = leaderboard
+    ///     .query(ScoreQuery::new().window(TimeWindow::Today).top(10))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(leaderboard = %self.id), err)
+    )]
+    pub async fn query(&self, query: ScoreQuery) -> Result<Vec<Score>, JornetError> {
+        self.inner_query(query).await
+    }
+
+    async fn inner_query(&self, query: ScoreQuery) -> Result<Vec<Score>, JornetError> {
+        let path = query.path(self.id);
+        let scores = self
+            .requests
+            .run(&path, || http::get(&self.http, &path))
+            .await?;
+        let mut scores = query.apply(self.order, scores);
+        self.anonymizer().apply(&mut scores);
+        Ok(scores)
+    }
+
+    /// Send the event matching the result of an operation to the listener.
+    fn report<T>(
+        &self,
+        operation: Operation,
+        result: Result<T, JornetError>,
+        event: impl FnOnce(&T) -> LeaderboardEvent,
+    ) -> Result<T, JornetError> {
+        if let Some(listener) = &self.listener {
+            (listener.0)(&match &result {
+                Ok(value) => event(value),
+                Err(error) => LeaderboardEvent::Failed {
+                    operation,
+                    error: error.clone(),
+                },
+            });
+        }
+        result
+    }
+
+    /// Configuration to send the requests of an operation, reporting their retries to the
+    /// listener.
+    fn http_for(&self, operation: Operation) -> Cow<'_, HttpConfig> {
+        match &self.listener {
+            Some(listener) => {
+                let listener = listener.clone();
+                Cow::Owned(HttpConfig {
+                    on_retry: Some(RetryHook(Arc::new(move |attempt, error| {
+                        (listener.0)(&LeaderboardEvent::Retrying {
+                            operation,
+                            attempt,
+                            error: error.clone(),
+                        })
+                    }))),
+                    ..self.http.clone()
+                })
+            }
+            None => Cow::Borrowed(&self.http),
         }
     }
 }
 
 /// A score from a leaderboard
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect, bevy_reflect::FromReflect)
+)]
 pub struct Score {
-    /// The score.
+    /// ID of the score, if the server exposes it.
+    #[serde(default)]
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    pub id: Option<Uuid>,
+    /// The score. For integer scores, this is an approximation of [`Self::score_i64`].
     pub score: f32,
+    /// The score, if it was sent as an integer.
+    #[serde(default)]
+    pub score_i64: Option<i64>,
     /// The player name.
     pub player: String,
+    /// ID of the player, if the server exposes it.
+    #[serde(default)]
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    pub player_id: Option<Uuid>,
     /// Optional metadata.
     pub meta: Option<String>,
-    /// Timestamp of the score.
+    /// Named secondary values of the score.
+    #[serde(default)]
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    pub dimensions: BTreeMap<String, f64>,
+    /// Where to download the attachment of the score, if it has one. The URL can be relative to
+    /// the host of the leaderboard. See [`Leaderboard::download_attachment`].
+    #[serde(default)]
+    pub attachment_url: Option<String>,
+    /// Sub-board of the leaderboard the score was sent to, if any. See
+    /// [`Leaderboard::send_score_for`].
+    #[serde(default)]
+    pub group: Option<String>,
+    /// Season the score was sent in, if any. See [`Leaderboard::get_current_season`].
+    #[serde(default)]
+    pub season: Option<String>,
+    /// Team of the player, if any. See [`Leaderboard::get_team_leaderboard`].
+    #[serde(default)]
+    pub team: Option<String>,
+    /// Country or region of the player when the score was sent, if any. See
+    /// [`Player::region`].
+    #[serde(default)]
+    pub region: Option<String>,
+    /// Timestamp of the score, in RFC 3339 format. With the `time` feature, it can be parsed
+    /// with [`Self::datetime`].
     pub timestamp: String,
+    /// Rank of the score in the leaderboard, starting at 1. If the server doesn't send it, it's
+    /// computed from the scores received, according to the [order](ScoreOrder) of the
+    /// leaderboard.
+    #[serde(default)]
+    pub rank: Option<u32>,
 }
 
-#[derive(Serialize)]
+impl Score {
+    /// Get a secondary value sent with [`Leaderboard::send_score_with_dimensions`].
+    pub fn dimension(&self, name: &str) -> Option<f64> {
+        self.dimensions.get(name).copied()
+    }
+
+    /// Deserialize metadata sent with [`Leaderboard::send_score_with_meta_typed`].
+    ///
+    /// Returns `Ok(None)` if the score has no metadata.
+    pub fn meta_as<T: DeserializeOwned>(&self) -> Result<Option<T>, JornetError> {
+        self.meta
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|error| JornetError::Deserialization(error.to_string()))
+    }
+
+    /// The flag of the region of the player, as an emoji like 🇫🇷, if the score has a region
+    /// that is a valid two-letter code.
+    pub fn flag(&self) -> Option<String> {
+        let region = self.region.as_deref()?;
+        if region.len() != 2 || !region.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        // flags are pairs of regional indicator symbols, one for each letter of the code
+        region
+            .chars()
+            .map(|c| char::from_u32(0x1F1E6 + (c.to_ascii_uppercase() as u32 - 'A' as u32)))
+            .collect()
+    }
+
+    /// When the score was achieved, in seconds since the Unix epoch.
+    pub fn unix_timestamp(&self) -> Option<i64> {
+        timestamp::parse_rfc3339(&self.timestamp)
+    }
+
+    /// When the score was achieved.
+    #[cfg(feature = "time")]
+    pub fn datetime(&self) -> Result<time::OffsetDateTime, JornetError> {
+        time::OffsetDateTime::parse(
+            &self.timestamp,
+            &time::format_description::well_known::Rfc3339,
+        )
+        .map_err(|error| JornetError::Deserialization(error.to_string()))
+    }
+
+    /// Whether two scores are the same entry of the leaderboard.
+    fn is_same(&self, other: &Score) -> bool {
+        match (self.id, other.id) {
+            (Some(id), Some(other_id)) => id == other_id,
+            _ => {
+                self.player == other.player
+                    && self.value() == other.value()
+                    && self.meta == other.meta
+                    && self.timestamp == other.timestamp
+            }
+        }
+    }
+
+    /// The score as a duration, if it was sent with [`Leaderboard::send_duration`].
+    pub fn as_duration(&self) -> Option<Duration> {
+        self.score_i64
+            .and_then(|millis| u64::try_from(millis).ok())
+            .map(Duration::from_millis)
+    }
+
+    /// The value of the score, as it was sent.
+    pub fn value(&self) -> ScoreValue {
+        match self.score_i64 {
+            Some(score) => ScoreValue::Integer(score),
+            None => ScoreValue::Float(self.score),
+        }
+    }
+}
+
+/// Result of a score submission, as reported by the server.
+///
+/// Servers that don't report on submissions leave every field empty.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct SubmittedScore {
+    /// ID assigned to the score by the server.
+    pub id: Option<Uuid>,
+    /// Rank of the score in the leaderboard, starting at 1.
+    pub rank: Option<u32>,
+    /// Whether the score beat the previous best score of the player.
+    pub personal_best: Option<bool>,
+    /// Whether the server couldn't be reached and the score was kept in the offline queue, to
+    /// be sent later. See [`LeaderboardBuilder::offline_queue`].
+    #[serde(skip)]
+    pub queued: bool,
+    /// Whether the server already received this score, identified by its nonce. See
+    /// [`LeaderboardBuilder::nonces`].
+    pub replayed: bool,
+}
+
+/// Value of a score, either a float or an integer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScoreValue {
+    /// A float score, as sent with [`Leaderboard::send_score`].
+    Float(f32),
+    /// An integer score, as sent with [`Leaderboard::send_score_i64`].
+    Integer(i64),
+}
+
+impl ScoreValue {
+    /// The score as a float, losing precision for large integers.
+    pub fn as_f32(&self) -> f32 {
+        match self {
+            ScoreValue::Float(score) => *score,
+            ScoreValue::Integer(score) => *score as f32,
+        }
+    }
+
+    /// The score as a double precision float, losing precision for very large integers.
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            ScoreValue::Float(score) => *score as f64,
+            ScoreValue::Integer(score) => *score as f64,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq)]
 struct ScoreInput {
     pub score: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score_i64: Option<i64>,
     pub player: Uuid,
     pub meta: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dimensions: BTreeMap<String, f64>,
     pub timestamp: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub season: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// ID of the session whose secret signed the score, instead of the key of the player.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<Uuid>,
     pub k: String,
 }
 
 impl ScoreInput {
-    fn new(leaderboard_key: Uuid, score: f32, player: &Player, meta: Option<String>) -> Self {
-        #[cfg(not(target_arch = "wasm32"))]
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
-        #[cfg(target_arch = "wasm32")]
-        let timestamp = (js_sys::Date::now() / 1000.0) as u64;
+    fn new(
+        leaderboard_key: Uuid,
+        player: &Player,
+        session: Option<&Session>,
+        submission: ScoreSubmission,
+        idempotency_key: Option<Uuid>,
+        nonce: Option<Uuid>,
+    ) -> Self {
+        let ScoreSubmission {
+            value,
+            meta,
+            dimensions,
+            timestamp,
+            group,
+            season,
+            team,
+            ..
+        } = submission;
+        let score = value.as_f32();
+        let score_i64 = match value {
+            ScoreValue::Float(_) => None,
+            ScoreValue::Integer(score) => Some(score),
+        };
+        let timestamp = timestamp.unwrap_or_else(now_secs);
+        let region = player.region.clone();
+        let signing_key = session.map_or(player.key, |session| session.secret);
 
-        let mut mac = Hmac::<Sha256>::new_from_slice(player.key.as_bytes()).unwrap();
+        let mut mac = Hmac::<Sha256>::new_from_slice(signing_key.as_bytes()).unwrap();
         mac.update(&timestamp.to_le_bytes());
         mac.update(leaderboard_key.as_bytes());
         mac.update(player.id.as_bytes());
@@ -141,30 +2038,268 @@ impl ScoreInput {
         if let Some(meta) = meta.as_ref() {
             mac.update(meta.as_bytes());
         }
+        // fields added after the first version are signed after the metadata, so that the
+        // signature of scores not using them is unchanged
+        if let Some(score_i64) = score_i64 {
+            mac.update(&score_i64.to_le_bytes());
+        }
+        if let Some(idempotency_key) = idempotency_key {
+            mac.update(idempotency_key.as_bytes());
+        }
+        // dimensions are signed in the order of their names, each name prefixed by its length
+        for (name, value) in &dimensions {
+            mac.update(&(name.len() as u64).to_le_bytes());
+            mac.update(name.as_bytes());
+            mac.update(&value.to_le_bytes());
+        }
+        if let Some(nonce) = nonce {
+            mac.update(nonce.as_bytes());
+        }
+        if let Some(group) = group.as_ref() {
+            mac.update(&(group.len() as u64).to_le_bytes());
+            mac.update(group.as_bytes());
+        }
+        // later strings are signed after their name, so that they can't be confused with a group
+        for (name, value) in [("season", &season), ("team", &team), ("region", &region)] {
+            if let Some(value) = value {
+                mac.update(name.as_bytes());
+                mac.update(&(value.len() as u64).to_le_bytes());
+                mac.update(value.as_bytes());
+            }
+        }
 
         let hmac = hex::encode(&mac.finalize().into_bytes()[..]);
         Self {
             score,
+            score_i64,
             player: player.id,
             meta,
+            dimensions,
             timestamp,
+            idempotency_key,
+            nonce,
+            group,
+            season,
+            team,
+            region,
+            session: session.map(|session| session.id),
             k: hmac,
         }
     }
 }
 
+/// Request to delete a score, signed by the player who sent it.
+#[derive(Serialize)]
+struct ScoreDeletion {
+    pub player: Uuid,
+    pub timestamp: u64,
+    pub k: String,
+}
+
+impl ScoreDeletion {
+    fn new(leaderboard_key: Uuid, score_id: Uuid, player: &Player) -> Self {
+        let timestamp = now_secs();
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(player.key.as_bytes()).unwrap();
+        mac.update(&timestamp.to_le_bytes());
+        mac.update(leaderboard_key.as_bytes());
+        mac.update(player.id.as_bytes());
+        mac.update(score_id.as_bytes());
+
+        let hmac = hex::encode(&mac.finalize().into_bytes()[..]);
+        Self {
+            player: player.id,
+            timestamp,
+            k: hmac,
+        }
+    }
+}
+
+/// Request to change the name of a player, signed by the player.
+#[derive(Serialize)]
+struct PlayerRename {
+    pub name: String,
+    pub timestamp: u64,
+    pub k: String,
+}
+
+impl PlayerRename {
+    fn new(name: &str, player: &Player) -> Self {
+        let timestamp = now_secs();
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(player.key.as_bytes()).unwrap();
+        mac.update(&timestamp.to_le_bytes());
+        mac.update(player.id.as_bytes());
+        // length-prefixed so that the signature can't be mistaken for a deletion
+        mac.update(&(name.len() as u64).to_le_bytes());
+        mac.update(name.as_bytes());
+
+        let hmac = hex::encode(&mac.finalize().into_bytes()[..]);
+        Self {
+            name: name.to_string(),
+            timestamp,
+            k: hmac,
+        }
+    }
+}
+
+/// Request to delete a player and their scores, signed by the player.
+#[derive(Serialize)]
+struct PlayerDeletion {
+    pub timestamp: u64,
+    pub k: String,
+}
+
+impl PlayerDeletion {
+    fn new(player: &Player) -> Self {
+        let timestamp = now_secs();
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(player.key.as_bytes()).unwrap();
+        mac.update(&timestamp.to_le_bytes());
+        mac.update(player.id.as_bytes());
+
+        let hmac = hex::encode(&mac.finalize().into_bytes()[..]);
+        Self { timestamp, k: hmac }
+    }
+}
+
+/// Request to link a player to an external account, signed by the player.
+#[derive(Serialize)]
+struct AccountLink {
+    pub provider: String,
+    pub token: String,
+    pub timestamp: u64,
+    pub k: String,
+}
+
+impl AccountLink {
+    fn new(provider: &str, token: &str, player: &Player) -> Self {
+        let timestamp = now_secs();
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(player.key.as_bytes()).unwrap();
+        mac.update(&timestamp.to_le_bytes());
+        mac.update(player.id.as_bytes());
+        // not length-prefixed, so that the signature can't be mistaken for a rename
+        mac.update(b"link");
+        for value in [provider, token] {
+            mac.update(&(value.len() as u64).to_le_bytes());
+            mac.update(value.as_bytes());
+        }
+
+        let hmac = hex::encode(&mac.finalize().into_bytes()[..]);
+        Self {
+            provider: provider.to_string(),
+            token: token.to_string(),
+            timestamp,
+            k: hmac,
+        }
+    }
+}
+
+/// Request to connect as the player linked to an external account.
+#[derive(Serialize)]
+struct AccountLogin {
+    provider: String,
+    token: String,
+}
+
+/// Current time, in seconds since the Unix epoch.
+fn now_secs() -> u64 {
+    #[cfg(not(target_arch = "wasm32"))]
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    #[cfg(target_arch = "wasm32")]
+    let timestamp = (js_sys::Date::now() / 1000.0) as u64;
+
+    timestamp
+}
+
 /// A player, as returned from the server
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect, bevy_reflect::FromReflect)
+)]
 pub struct Player {
     /// its ID
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
     pub id: Uuid,
     /// its key, this should be kept secret
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
     pub key: Uuid,
-    /// its name, changing it here won't be reflected on the server
+    /// its name, changing it here won't be reflected on the server, see
+    /// [`Leaderboard::rename_player`]
     pub name: String,
+    /// URL of its avatar, for players connected with an account that has one, like Discord
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    /// its country or region, as an uppercase ISO 3166-1 alpha-2 code like `FR`, sent with its
+    /// scores, see [`Leaderboard::set_player_region`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+impl Player {
+    /// Save the player, to connect as the same player the next time the game starts with
+    /// [`Self::load_from`].
+    ///
+    /// On native targets, `location` is the path of the file where the player is saved, which
+    /// is replaced atomically. On wasm, it is the key used in `localStorage`. Games hosted on a
+    /// shared domain, like itch.io, should include the name of the game in the key so that they
+    /// don't share players.
+    pub fn save_to(&self, location: &str) -> Result<(), JornetError> {
+        self.save_protected(location, &CredentialProtection::None)
+    }
+
+    /// Load a player saved with [`Self::save_to`]. Returns `None` if no player is saved at
+    /// `location`.
+    pub fn load_from(location: &str) -> Result<Option<Self>, JornetError> {
+        Self::load_protected(location, &CredentialProtection::None)
+    }
+
+    /// Save the player like [`Self::save_to`], protecting its key with `protection`.
+    pub fn save_protected(
+        &self,
+        location: &str,
+        protection: &CredentialProtection,
+    ) -> Result<(), JornetError> {
+        // serializing a player - can't fail
+        protection.write(location, &serde_json::to_string(self).unwrap())
+    }
+
+    /// Load a player saved with [`Self::save_protected`], with the same `protection`. Returns
+    /// `None` if no player is saved at `location`.
+    pub fn load_protected(
+        location: &str,
+        protection: &CredentialProtection,
+    ) -> Result<Option<Self>, JornetError> {
+        protection
+            .read(location)?
+            .map(|data| {
+                serde_json::from_str(&data)
+                    .map_err(|error| JornetError::Deserialization(error.to_string()))
+            })
+            .transpose()
+    }
 }
 
 #[derive(Serialize, Debug, Clone)]
 struct PlayerInput {
     name: Option<String>,
+    /// Whether the server must reserve the name, failing if another player has it.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    unique: bool,
+}
+
+#[derive(Deserialize, Debug)]
+struct RegionLookup {
+    #[serde(default)]
+    region: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct NameAvailability {
+    available: bool,
 }
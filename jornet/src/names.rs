@@ -0,0 +1,283 @@
+use std::{fmt, sync::Arc};
+
+use crate::{builder::random_fraction, JornetError};
+
+/// Word lists of generated names for each language, in the order they are combined, and the
+/// separator between words.
+const LANGUAGES: &[(&str, &[&[&str]], &str)] = &[
+    (
+        "en",
+        &[
+            &[
+                "Brave", "Sleepy", "Swift", "Clever", "Tiny", "Mighty", "Quiet", "Happy", "Red",
+                "Blue",
+            ],
+            &[
+                "Cat", "Dog", "Fox", "Owl", "Rabbit", "Dragon", "Knight", "Wizard", "Penguin",
+                "Panda",
+            ],
+        ],
+        " ",
+    ),
+    (
+        "ja",
+        &[
+            &[
+                "はやい",
+                "ねむい",
+                "つよい",
+                "かしこい",
+                "ちいさな",
+                "おおきな",
+                "しずかな",
+                "げんきな",
+                "あかい",
+                "あおい",
+            ],
+            &[
+                "ネコ",
+                "イヌ",
+                "キツネ",
+                "タヌキ",
+                "ウサギ",
+                "ドラゴン",
+                "サムライ",
+                "ニンジャ",
+                "ペンギン",
+                "パンダ",
+            ],
+        ],
+        "",
+    ),
+    (
+        "fr",
+        &[
+            &[
+                "Chat",
+                "Renard",
+                "Hibou",
+                "Lapin",
+                "Dragon",
+                "Chevalier",
+                "Loup",
+                "Panda",
+            ],
+            &[
+                "Rapide", "Malin", "Brave", "Joyeux", "Calme", "Rouge", "Bleu", "Endormi",
+            ],
+        ],
+        " ",
+    ),
+    (
+        "es",
+        &[
+            &[
+                "Gato",
+                "Zorro",
+                "Búho",
+                "Conejo",
+                "Dragón",
+                "Caballero",
+                "Lobo",
+                "Oso",
+            ],
+            &[
+                "Veloz",
+                "Valiente",
+                "Listo",
+                "Feliz",
+                "Tranquilo",
+                "Rojo",
+                "Azul",
+                "Dormido",
+            ],
+        ],
+        " ",
+    ),
+    (
+        "de",
+        &[
+            &[
+                "Schneller",
+                "Mutiger",
+                "Kluger",
+                "Kleiner",
+                "Großer",
+                "Stiller",
+                "Roter",
+                "Blauer",
+            ],
+            &[
+                "Fuchs", "Hund", "Drache", "Ritter", "Bär", "Wolf", "Adler", "Pinguin",
+            ],
+        ],
+        " ",
+    ),
+];
+
+/// Generates names for players created without one, instead of the server. See
+/// [`LeaderboardBuilder::name_generator`](crate::LeaderboardBuilder::name_generator).
+///
+/// ```
+/// let generator = jornet::NameGenerator::from_words(
+///     &[&["Brave", "Sleepy", "Ancient"], &["Dragon", "Wizard", "Golem"]],
+///     " ",
+/// );
+/// let name = generator.generate(); // "Sleepy Golem"
+/// ```
+#[derive(Clone)]
+pub struct NameGenerator(Arc<dyn Fn() -> String + Send + Sync>);
+
+impl NameGenerator {
+    /// Generate names by calling `generate`.
+    pub fn new(generate: impl Fn() -> String + Send + Sync + 'static) -> Self {
+        Self(Arc::new(generate))
+    }
+
+    /// Generate names by picking a random word in each list, joined by `separator`.
+    pub fn from_words(lists: &[&[&str]], separator: &str) -> Self {
+        let lists: Vec<Vec<String>> = lists
+            .iter()
+            .filter(|words| !words.is_empty())
+            .map(|words| words.iter().map(|word| word.to_string()).collect())
+            .collect();
+        let separator = separator.to_string();
+        Self::new(move || {
+            lists
+                .iter()
+                .map(|words| {
+                    let index = (random_fraction() * words.len() as f64) as usize;
+                    words[index.min(words.len() - 1)].as_str()
+                })
+                .collect::<Vec<_>>()
+                .join(&separator)
+        })
+    }
+
+    /// Generate names in the language of `locale`, like `ja` or `fr-CA`, from built-in word
+    /// lists. Only the language is considered, and languages without word lists fall back to
+    /// English. See [`Self::locales`] for the supported languages.
+    pub fn for_locale(locale: &str) -> Self {
+        let language = language(locale);
+        let (_, lists, separator) = LANGUAGES
+            .iter()
+            .find(|(code, _, _)| *code == language)
+            .unwrap_or(&LANGUAGES[0]);
+        Self::from_words(lists, separator)
+    }
+
+    /// Generate names in the language of the system, or of the browser on wasm, falling back to
+    /// English. See [`Self::for_locale`].
+    ///
+    /// On native targets, the locale is read from the `LC_ALL`, `LC_MESSAGES` and `LANG`
+    /// environment variables.
+    pub fn from_system_locale() -> Self {
+        Self::for_locale(&system_locale().unwrap_or_default())
+    }
+
+    /// Languages with built-in word lists, as lowercase language codes.
+    pub fn locales() -> impl Iterator<Item = &'static str> {
+        LANGUAGES.iter().map(|(code, _, _)| *code)
+    }
+
+    /// Generate a name.
+    pub fn generate(&self) -> String {
+        (self.0)()
+    }
+}
+
+impl fmt::Debug for NameGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NameGenerator")
+    }
+}
+
+/// Rejects inappropriate names chosen by players, before they are sent to the server. See
+/// [`LeaderboardBuilder::name_filter`](crate::LeaderboardBuilder::name_filter).
+///
+/// ```
+/// let filter = jornet::NameFilter::from_words(&["badword"]);
+/// assert!(filter.check("B4dW0rd_99").is_err());
+/// assert!(filter.check("Ferris").is_ok());
+/// ```
+#[derive(Clone)]
+pub struct NameFilter(Arc<FilterFn>);
+
+type FilterFn = dyn Fn(&str) -> Result<(), String> + Send + Sync;
+
+impl NameFilter {
+    /// Filter names with `filter`, returning why a name is rejected.
+    pub fn new(filter: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(filter))
+    }
+
+    /// Reject names containing one of `words`, ignoring case, separators and common letter
+    /// substitutions like `4` for `a`. Words are matched anywhere in the name, so short words
+    /// can reject innocent names that contain them.
+    pub fn from_words(words: &[&str]) -> Self {
+        let words: Vec<String> = words
+            .iter()
+            .map(|word| normalize(word))
+            .filter(|word| !word.is_empty())
+            .collect();
+        Self::new(move |name| {
+            let name = normalize(name);
+            match words.iter().any(|word| name.contains(word.as_str())) {
+                true => Err("contains a blocked word".to_string()),
+                false => Ok(()),
+            }
+        })
+    }
+
+    /// Check a name, failing with [`JornetError::NameRejected`] if it's rejected. This can be
+    /// used to validate a name as the player types it.
+    pub fn check(&self, name: &str) -> Result<(), JornetError> {
+        (self.0)(name).map_err(JornetError::NameRejected)
+    }
+}
+
+impl fmt::Debug for NameFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NameFilter")
+    }
+}
+
+/// Lowercase a name, undo common letter substitutions, and remove everything but letters.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            '0' => 'o',
+            '1' | '!' | '|' => 'i',
+            '3' => 'e',
+            '4' | '@' => 'a',
+            '5' | '$' => 's',
+            '7' => 't',
+            c => c,
+        })
+        .filter(|c| c.is_alphabetic())
+        .collect()
+}
+
+/// The language of a locale, like `fr` for `fr_CA.UTF-8`.
+fn language(locale: &str) -> String {
+    locale
+        .split(['-', '_', '.', '@'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn system_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        // `C` and `POSIX` are the default locale, without a language
+        .find(|locale| !locale.is_empty() && locale != "C" && locale != "POSIX")
+}
+
+#[cfg(target_arch = "wasm32")]
+fn system_locale() -> Option<String> {
+    web_sys::window()?.navigator().language()
+}
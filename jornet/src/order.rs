@@ -0,0 +1,92 @@
+use std::{cmp::Ordering, collections::HashSet};
+
+use serde::Deserialize;
+
+use crate::{Score, ScoreValue};
+
+/// Which scores are better in a leaderboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreOrder {
+    /// Higher scores are better, like points.
+    #[default]
+    HigherIsBetter,
+    /// Lower scores are better, like completion times.
+    LowerIsBetter,
+}
+
+impl ScoreOrder {
+    /// Compare two scores, the better one being [`Ordering::Less`] so that it's sorted first.
+    pub fn compare(&self, a: &Score, b: &Score) -> Ordering {
+        self.compare_values(a.value(), b.value())
+    }
+
+    /// Compare two score values, the better one being [`Ordering::Less`].
+    pub fn compare_values(&self, a: ScoreValue, b: ScoreValue) -> Ordering {
+        let ascending = match (a, b) {
+            (ScoreValue::Integer(a), ScoreValue::Integer(b)) => a.cmp(&b),
+            (a, b) => a.as_f64().total_cmp(&b.as_f64()),
+        };
+        match self {
+            ScoreOrder::HigherIsBetter => ascending.reverse(),
+            ScoreOrder::LowerIsBetter => ascending,
+        }
+    }
+
+    /// Whether `a` is strictly better than `b`.
+    pub fn is_better(&self, a: ScoreValue, b: ScoreValue) -> bool {
+        self.compare_values(a, b) == Ordering::Less
+    }
+
+    /// Set the rank of scores that don't have one, starting at 1 for the best score. Equal
+    /// scores share the same rank.
+    pub fn rank(&self, scores: &mut [Score]) {
+        if scores.iter().all(|score| score.rank.is_some()) {
+            return;
+        }
+        let mut sorted: Vec<usize> = (0..scores.len()).collect();
+        sorted.sort_by(|a, b| self.compare(&scores[*a], &scores[*b]));
+        let mut rank = 1;
+        for (position, index) in sorted.iter().enumerate() {
+            if position > 0
+                && self.compare(&scores[sorted[position - 1]], &scores[*index]) == Ordering::Less
+            {
+                rank = position as u32 + 1;
+            }
+            scores[*index].rank.get_or_insert(rank);
+        }
+    }
+
+    /// Keep only the best score of each player, sorted from the best. Players are identified
+    /// by their ID if the server exposes it, otherwise by their name.
+    ///
+    /// If scores were removed, ranks are computed again so that they are consecutive.
+    pub fn best_per_player(&self, mut scores: Vec<Score>) -> Vec<Score> {
+        if self.keep_best_per_player(&mut scores) {
+            for score in &mut scores {
+                score.rank = None;
+            }
+            self.rank(&mut scores);
+        }
+        scores
+    }
+
+    /// Keep only the best score of each player like [`Self::best_per_player`], without
+    /// changing the ranks. Returns whether scores were removed.
+    pub(crate) fn keep_best_per_player(&self, scores: &mut Vec<Score>) -> bool {
+        self.sort(scores);
+        let count = scores.len();
+        let mut ids = HashSet::new();
+        let mut names = HashSet::new();
+        scores.retain(|score| match score.player_id {
+            Some(id) => ids.insert(id),
+            None => names.insert(score.player.clone()),
+        });
+        scores.len() != count
+    }
+
+    /// Sort scores, the best first.
+    pub fn sort(&self, scores: &mut [Score]) {
+        scores.sort_by(|a, b| self.compare(a, b));
+    }
+}
@@ -0,0 +1,144 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+
+use crate::{http, JornetError, Leaderboard, Score, ScoreQuery};
+
+/// Number of scores requested in each page.
+const PAGE_SIZE: usize = 100;
+
+#[cfg(not(target_arch = "wasm32"))]
+type PageFuture<'a> = Pin<Box<dyn Future<Output = Result<Vec<Score>, JornetError>> + Send + 'a>>;
+#[cfg(target_arch = "wasm32")]
+type PageFuture<'a> = Pin<Box<dyn Future<Output = Result<Vec<Score>, JornetError>> + 'a>>;
+
+/// Scores of a leaderboard from the best, requested page by page as the stream is polled. See
+/// [`Leaderboard::scores_stream`](crate::Leaderboard::scores_stream).
+///
+/// The stream ends after the last score, or after the first error.
+pub struct ScorePages<'a> {
+    leaderboard: &'a Leaderboard,
+    /// Number of scores requested so far.
+    offset: usize,
+    scores: VecDeque<Score>,
+    page: Option<PageFuture<'a>>,
+    /// First score of the first page, as received, to tell if the server ignored the offset.
+    first: Option<Score>,
+    done: bool,
+}
+
+impl<'a> ScorePages<'a> {
+    pub(crate) fn new(leaderboard: &'a Leaderboard) -> Self {
+        Self {
+            leaderboard,
+            offset: 0,
+            scores: VecDeque::new(),
+            page: None,
+            first: None,
+            done: false,
+        }
+    }
+
+    /// Request the page starting at `offset`.
+    async fn fetch(leaderboard: &Leaderboard, offset: usize) -> Result<Vec<Score>, JornetError> {
+        let path = format!(
+            "/api/v1/scores/{}{}",
+            leaderboard.id,
+            http::query_string(&[
+                ("offset", offset.to_string()),
+                ("limit", PAGE_SIZE.to_string())
+            ])
+        );
+        http::get(&leaderboard.http, &path).await
+    }
+
+    /// Rank and sort a page received, and check whether it's the last one.
+    fn receive(&mut self, mut page: Vec<Score>) {
+        let order = self.leaderboard.order;
+        let repeated = match (&self.first, page.first()) {
+            (Some(first), Some(received)) => is_same(first, received),
+            _ => false,
+        };
+        if self.offset == 0 {
+            self.first = page.first().cloned();
+        }
+        if page.len() > PAGE_SIZE {
+            // the server doesn't support pagination and sent the whole leaderboard
+            page = ScoreQuery::new().apply(order, page);
+            page.drain(..self.offset.min(page.len()));
+            self.done = true;
+        } else if repeated {
+            // the server ignored the offset and sent the first page again, it was the whole
+            // leaderboard
+            page.clear();
+            self.done = true;
+        } else {
+            // ranks computed from a page start after the scores of the previous pages
+            let unranked: Vec<bool> = page.iter().map(|score| score.rank.is_none()).collect();
+            order.rank(&mut page);
+            for (score, unranked) in page.iter_mut().zip(unranked) {
+                if let (Some(rank), true) = (&mut score.rank, unranked) {
+                    *rank += self.offset as u32;
+                }
+            }
+            order.sort(&mut page);
+            self.done = page.len() < PAGE_SIZE;
+        }
+        self.offset += page.len();
+        self.leaderboard.anonymizer().apply(&mut page);
+        self.scores.extend(page);
+    }
+}
+
+/// Whether two scores received are the same score.
+fn is_same(a: &Score, b: &Score) -> bool {
+    (
+        a.id,
+        a.player_id,
+        &a.player,
+        a.score.to_bits(),
+        &a.timestamp,
+    ) == (
+        b.id,
+        b.player_id,
+        &b.player,
+        b.score.to_bits(),
+        &b.timestamp,
+    )
+}
+
+impl Stream for ScorePages<'_> {
+    type Item = Result<Score, JornetError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(score) = this.scores.pop_front() {
+                return Poll::Ready(Some(Ok(score)));
+            }
+            if this.done {
+                return Poll::Ready(None);
+            }
+            let page = this
+                .page
+                .get_or_insert_with(|| Box::pin(Self::fetch(this.leaderboard, this.offset)));
+            let result = match page.as_mut().poll(cx) {
+                Poll::Ready(result) => result,
+                Poll::Pending => return Poll::Pending,
+            };
+            this.page = None;
+            match result {
+                Ok(page) => this.receive(page),
+                Err(error) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(error)));
+                }
+            }
+        }
+    }
+}
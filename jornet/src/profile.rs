@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::timestamp;
+
+/// Public information about a player, that can be shown to other players. Unlike [`Player`],
+/// it doesn't hold the key of the player. See
+/// [`Leaderboard::get_player_profile`](crate::Leaderboard::get_player_profile).
+///
+/// [`Player`]: crate::Player
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PlayerProfile {
+    /// ID of the player.
+    pub id: Uuid,
+    /// Name of the player.
+    pub name: String,
+    /// When the player was created, in RFC 3339 format, if the server exposes it.
+    #[serde(default, alias = "created_at")]
+    pub joined_at: Option<String>,
+    /// URL of the avatar of the player, if they have one.
+    #[serde(default)]
+    pub avatar: Option<String>,
+    /// Number of scores sent by the player.
+    #[serde(default, alias = "scores")]
+    pub score_count: u64,
+}
+
+impl PlayerProfile {
+    /// When the player was created, in seconds since the Unix epoch.
+    pub fn joined_unix_timestamp(&self) -> Option<i64> {
+        self.joined_at.as_deref().and_then(timestamp::parse_rfc3339)
+    }
+}
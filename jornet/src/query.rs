@@ -0,0 +1,258 @@
+use uuid::Uuid;
+
+use crate::{http, now_secs, timestamp, Player, Score, ScoreOrder};
+
+/// Period of time to get scores from. Days, weeks and months are in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeWindow {
+    /// All the scores.
+    #[default]
+    AllTime,
+    /// Scores of the current day.
+    Today,
+    /// Scores of the current week, starting on Monday.
+    ThisWeek,
+    /// Scores of the current month.
+    ThisMonth,
+    /// Scores achieved since a timestamp in seconds since the Unix epoch, included.
+    Since(u64),
+    /// Scores between two timestamps in seconds since the Unix epoch, `from` included and `to`
+    /// excluded.
+    Between {
+        /// Start of the period.
+        from: u64,
+        /// End of the period.
+        to: u64,
+    },
+}
+
+impl TimeWindow {
+    /// Bounds of the period, in seconds since the Unix epoch.
+    fn bounds(&self) -> (Option<i64>, Option<i64>) {
+        let now = now_secs() as i64;
+        match self {
+            TimeWindow::AllTime => (None, None),
+            TimeWindow::Today => (Some(timestamp::start_of_day(now)), None),
+            TimeWindow::ThisWeek => (Some(timestamp::start_of_week(now)), None),
+            TimeWindow::ThisMonth => (Some(timestamp::start_of_month(now)), None),
+            TimeWindow::Since(from) => (Some(*from as i64), None),
+            TimeWindow::Between { from, to } => (Some(*from as i64), Some(*to as i64)),
+        }
+    }
+}
+
+/// Which scores to get with [`Leaderboard::query`](crate::Leaderboard::query).
+///
+/// Filters are sent to the server so that only the matching scores are downloaded, and are
+/// applied again to the scores received for servers that don't support them.
+///
+/// Ranks sent by the server are kept. Scores without one are only ranked locally when the
+/// whole leaderboard is received, with no filter other than the window, group and season.
+#[derive(Debug, Clone, Default)]
+pub struct ScoreQuery {
+    window: TimeWindow,
+    limit: Option<usize>,
+    best_per_player: bool,
+    /// ID and name of the player whose scores to get.
+    player: Option<(Uuid, String)>,
+    players: Vec<Uuid>,
+    name_prefix: Option<String>,
+    group: Option<String>,
+    season: Option<String>,
+    region: Option<String>,
+}
+
+impl ScoreQuery {
+    /// Query all the scores.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only get scores achieved during a period of time.
+    pub fn window(mut self, window: TimeWindow) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Only get the best `n` scores.
+    pub fn top(mut self, n: usize) -> Self {
+        self.limit = Some(n);
+        self
+    }
+
+    /// Only get the best score of each player, so that a player can't appear several times.
+    /// See [`ScoreOrder::best_per_player`].
+    pub fn best_per_player(mut self) -> Self {
+        self.best_per_player = true;
+        self
+    }
+
+    /// Only get the scores of a player. Ranks are still those of the scores in the whole
+    /// leaderboard.
+    pub fn player(mut self, player: &Player) -> Self {
+        self.player = Some((player.id, player.name.clone()));
+        self
+    }
+
+    /// Only get the scores of the players with the given IDs, like the friends of the current
+    /// player. Ranks are still those of the scores in the whole leaderboard.
+    ///
+    /// If the server doesn't support this filter, scores are filtered after being received,
+    /// which needs the server to send the ID of the player of each score.
+    pub fn players(mut self, ids: &[Uuid]) -> Self {
+        self.players = ids.to_vec();
+        self
+    }
+
+    /// Only get the scores of players whose name starts with `prefix`, ignoring case. Ranks
+    /// are still those of the scores in the whole leaderboard.
+    pub fn search(mut self, prefix: &str) -> Self {
+        self.name_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Only get the scores of a sub-board of the leaderboard, ranked within it. See
+    /// [`Leaderboard::send_score_for`](crate::Leaderboard::send_score_for).
+    pub fn group(mut self, group: &str) -> Self {
+        self.group = Some(group.to_string());
+        self
+    }
+
+    /// Only get the scores of a season, ranked within it. See
+    /// [`Leaderboard::get_seasons`](crate::Leaderboard::get_seasons).
+    pub fn season(mut self, season: &str) -> Self {
+        self.season = Some(season.to_string());
+        self
+    }
+
+    /// Only get the scores sent by players of a country or region, like `FR`, ignoring case.
+    /// Ranks are still those of the scores in the whole leaderboard. See
+    /// [`Leaderboard::set_player_region`](crate::Leaderboard::set_player_region).
+    ///
+    /// If the server doesn't support this filter, scores are filtered after being received,
+    /// which needs the server to send the region of each score.
+    pub fn region(mut self, region: &str) -> Self {
+        self.region = Some(region.to_ascii_uppercase());
+        self
+    }
+
+    /// Query parameters sent to the server.
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        let mut parameters = vec![];
+        let (from, to) = self.window.bounds();
+        if let Some(from) = from {
+            parameters.push(("from", from.to_string()));
+        }
+        if let Some(to) = to {
+            parameters.push(("to", to.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            parameters.push(("limit", limit.to_string()));
+        }
+        if self.best_per_player {
+            parameters.push(("best_per_player", "true".to_string()));
+        }
+        if let Some((id, _)) = &self.player {
+            parameters.push(("player", id.to_string()));
+        }
+        if !self.players.is_empty() {
+            let ids: Vec<String> = self.players.iter().map(Uuid::to_string).collect();
+            parameters.push(("players", ids.join(",")));
+        }
+        if let Some(prefix) = &self.name_prefix {
+            parameters.push(("search", prefix.clone()));
+        }
+        if let Some(group) = &self.group {
+            parameters.push(("group", group.clone()));
+        }
+        if let Some(season) = &self.season {
+            parameters.push(("season", season.clone()));
+        }
+        if let Some(region) = &self.region {
+            parameters.push(("region", region.clone()));
+        }
+        parameters
+    }
+
+    /// Filter, rank and sort scores received from the server. Ranks sent by the server are
+    /// kept, and the others are only computed when the server sent the whole leaderboard.
+    pub(crate) fn apply(&self, order: ScoreOrder, mut scores: Vec<Score>) -> Vec<Score> {
+        self.filter_window(&mut scores);
+        if let Some(group) = &self.group {
+            // keep scores whose group isn't sent, as the server already filtered them
+            scores.retain(|score| score.group.as_ref().is_none_or(|other| other == group));
+        }
+        if let Some(season) = &self.season {
+            scores.retain(|score| score.season.as_ref().is_none_or(|other| other == season));
+        }
+        // the server only sends part of the leaderboard for the other filters, whose positions
+        // can't be known here: ranks it didn't send are left out rather than wrong
+        let whole_leaderboard = self.limit.is_none()
+            && self.player.is_none()
+            && self.players.is_empty()
+            && self.name_prefix.is_none()
+            && self.region.is_none();
+        if self.best_per_player && order.keep_best_per_player(&mut scores) && whole_leaderboard {
+            for score in &mut scores {
+                score.rank = None;
+            }
+        }
+        if whole_leaderboard {
+            order.rank(&mut scores);
+        }
+        // filtered after ranking, for servers that don't support these filters
+        if let Some((id, name)) = &self.player {
+            scores.retain(|score| match score.player_id {
+                Some(player_id) => &player_id == id,
+                None => &score.player == name,
+            });
+        }
+        if !self.players.is_empty() {
+            scores.retain(|score| {
+                score
+                    .player_id
+                    .is_some_and(|player_id| self.players.contains(&player_id))
+            });
+        }
+        if let Some(region) = &self.region {
+            scores.retain(|score| {
+                score
+                    .region
+                    .as_ref()
+                    .is_some_and(|other| other.eq_ignore_ascii_case(region))
+            });
+        }
+        if let Some(prefix) = &self.name_prefix {
+            let prefix = prefix.to_lowercase();
+            scores.retain(|score| score.player.to_lowercase().starts_with(&prefix));
+        }
+        order.sort(&mut scores);
+        if let Some(limit) = self.limit {
+            scores.truncate(limit);
+        }
+        scores
+    }
+
+    /// Remove scores outside of the time window.
+    pub(crate) fn filter_window(&self, scores: &mut Vec<Score>) {
+        let (from, to) = self.window.bounds();
+        if from.is_some() || to.is_some() {
+            scores.retain(|score| match score.unix_timestamp() {
+                Some(timestamp) => {
+                    from.is_none_or(|from| timestamp >= from) && to.is_none_or(|to| timestamp < to)
+                }
+                // keep scores whose timestamp can't be read rather than losing them
+                None => true,
+            });
+        }
+    }
+
+    /// Path to get the scores of a leaderboard matching this query.
+    pub(crate) fn path(&self, leaderboard: Uuid) -> String {
+        format!(
+            "/api/v1/scores/{}{}",
+            leaderboard,
+            http::query_string(&self.parameters())
+        )
+    }
+}
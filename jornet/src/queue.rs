@@ -0,0 +1,112 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Mutex,
+};
+
+use uuid::Uuid;
+
+use crate::{storage, JornetError, ScoreInput};
+
+/// Scores that couldn't be sent, persisted to be sent again later.
+pub(crate) struct OfflineQueue {
+    location: String,
+    /// Held while reading or writing the queue, so that concurrent submissions don't lose
+    /// scores.
+    lock: Mutex<()>,
+    /// Set while the queue is being sent, so that its scores are only sent once.
+    flushing: AtomicBool,
+}
+
+/// Guard of a sending of the queue, allowing the next one when dropped.
+pub(crate) struct Flush<'a>(&'a AtomicBool);
+
+impl Drop for Flush<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+impl OfflineQueue {
+    pub(crate) fn new(location: String) -> Self {
+        Self {
+            location,
+            lock: Mutex::new(()),
+            flushing: AtomicBool::new(false),
+        }
+    }
+
+    /// Whether a submission that failed with this error can succeed later. Errors answered by
+    /// the server, even server errors, could be answered again for the same score, which would
+    /// then block the queue.
+    pub(crate) fn should_queue(error: &JornetError) -> bool {
+        matches!(
+            error,
+            JornetError::Network(_) | JornetError::Timeout | JornetError::RateLimited { .. }
+        )
+    }
+
+    /// Add a score at the end of the queue.
+    pub(crate) fn push(&self, score: ScoreInput) -> Result<(), JornetError> {
+        let _lock = self.lock.lock().unwrap();
+        let mut scores = self.read()?;
+        scores.push(score);
+        self.write(&scores)
+    }
+
+    /// Start sending the queue, or `None` if it is already being sent.
+    pub(crate) fn flush(&self) -> Option<Flush<'_>> {
+        (!self.flushing.swap(true, Ordering::Acquire)).then_some(Flush(&self.flushing))
+    }
+
+    /// Get the first score of the queue, leaving it there until it is [removed](Self::remove).
+    pub(crate) fn first(&self) -> Result<Option<ScoreInput>, JornetError> {
+        let _lock = self.lock.lock().unwrap();
+        Ok(self.read()?.into_iter().next())
+    }
+
+    /// Remove a score from the queue, once the server answered it.
+    pub(crate) fn remove(&self, score: &ScoreInput) -> Result<(), JornetError> {
+        let _lock = self.lock.lock().unwrap();
+        let mut scores = self.read()?;
+        if let Some(index) = scores.iter().position(|queued| queued == score) {
+            scores.remove(index);
+            self.write(&scores)?;
+        }
+        Ok(())
+    }
+
+    /// Remove the scores of a player from the queue.
+    pub(crate) fn remove_player(&self, player: Uuid) -> Result<(), JornetError> {
+        let _lock = self.lock.lock().unwrap();
+        let mut scores = self.read()?;
+        let count = scores.len();
+        scores.retain(|score| score.player != player);
+        if scores.len() != count {
+            self.write(&scores)?;
+        }
+        Ok(())
+    }
+
+    /// Read the queue. A queue that can't be parsed is moved aside to `<location>.corrupt`,
+    /// so that its scores can be recovered instead of being overwritten by the next one.
+    fn read(&self) -> Result<Vec<ScoreInput>, JornetError> {
+        let Some(data) = storage::read(&self.location) else {
+            return Ok(vec![]);
+        };
+        match serde_json::from_str(&data) {
+            Ok(scores) => Ok(scores),
+            Err(_error) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %_error, "moving aside an offline queue that can't be read");
+                storage::write(&format!("{}.corrupt", self.location), &data)?;
+                storage::remove(&self.location)?;
+                Ok(vec![])
+            }
+        }
+    }
+
+    fn write(&self, scores: &[ScoreInput]) -> Result<(), JornetError> {
+        // serializing scores - can't fail
+        storage::write(&self.location, &serde_json::to_string(scores).unwrap())
+    }
+}
@@ -0,0 +1,107 @@
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::{now_secs, Player};
+
+/// Crockford's base32 alphabet, without letters that can be mistaken for digits.
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Number of characters between dashes in a code encoded locally.
+const GROUP: usize = 4;
+
+/// Number of characters of a code encoded locally, holding the ID and key of the player.
+const LOCAL_CODE_LEN: usize = (32 * 8_usize).div_ceil(5);
+
+/// Request to create a recovery code, signed by the player.
+#[derive(Serialize)]
+pub(crate) struct RecoveryRequest {
+    pub timestamp: u64,
+    pub k: String,
+}
+
+impl RecoveryRequest {
+    pub(crate) fn new(player: &Player) -> Self {
+        let timestamp = now_secs();
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(player.key.as_bytes()).unwrap();
+        mac.update(&timestamp.to_le_bytes());
+        mac.update(player.id.as_bytes());
+        // not length-prefixed, so that the signature can't be mistaken for a deletion or a rename
+        mac.update(b"recovery");
+
+        let hmac = hex::encode(&mac.finalize().into_bytes()[..]);
+        Self { timestamp, k: hmac }
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RecoveryCode {
+    pub(crate) code: String,
+}
+
+/// Request to connect as the player a recovery code was created for.
+#[derive(Serialize)]
+pub(crate) struct Recovery<'a> {
+    pub code: &'a str,
+}
+
+/// Encode the ID and key of a player in a code, for servers that can't create one.
+pub(crate) fn encode(player: &Player) -> String {
+    let mut bytes = player.id.as_bytes().to_vec();
+    bytes.extend_from_slice(player.key.as_bytes());
+
+    let mut code = String::new();
+    let (mut buffer, mut bits) = (0u16, 0);
+    for byte in bytes {
+        buffer = (buffer << 8) | byte as u16;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            code.push(ALPHABET[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        code.push(ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+
+    code.as_bytes()
+        .chunks(GROUP)
+        // the alphabet is ASCII - can't fail
+        .map(|group| std::str::from_utf8(group).unwrap())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Decode the ID and key of a player from a code encoded with [`encode`], ignoring case,
+/// dashes and spaces, and reading letters mistaken for digits as the digit. Returns `None` if
+/// the code wasn't encoded locally.
+pub(crate) fn decode(code: &str) -> Option<(Uuid, Uuid)> {
+    let values = code
+        .chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .map(|c| match c.to_ascii_uppercase() {
+            'O' => Some(0),
+            'I' | 'L' => Some(1),
+            c => ALPHABET.iter().position(|a| *a as char == c),
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if values.len() != LOCAL_CODE_LEN {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(32);
+    let (mut buffer, mut bits) = (0u16, 0);
+    for value in values {
+        buffer = (buffer << 5) | value as u16;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    let id = Uuid::from_slice(&bytes[..16]).ok()?;
+    let key = Uuid::from_slice(&bytes[16..32]).ok()?;
+    Some((id, key))
+}
@@ -0,0 +1,146 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Poll, Waker},
+    time::Duration,
+};
+
+use crate::{
+    anonymize::Anonymizer,
+    events::EventListener,
+    http::{self, Conditional, HttpConfig},
+    JornetError, LeaderboardDiff, LeaderboardEvent, Operation, Score, ScoreOrder,
+};
+
+/// Handle to a leaderboard refreshed in the background. See
+/// [`Leaderboard::start_auto_refresh`](crate::Leaderboard::start_auto_refresh).
+///
+/// Refreshing stops when the handle is dropped.
+pub struct AutoRefresh {
+    state: Arc<Mutex<RefreshState>>,
+    stopped: Arc<AtomicBool>,
+}
+
+#[derive(Default)]
+struct RefreshState {
+    scores: Option<Vec<Score>>,
+    /// Whether the scores changed since they were last taken.
+    changed: bool,
+    error: Option<JornetError>,
+    waker: Option<Waker>,
+}
+
+impl AutoRefresh {
+    pub(crate) fn start(
+        http: HttpConfig,
+        path: String,
+        order: ScoreOrder,
+        anonymizer: Anonymizer,
+        listener: Option<EventListener>,
+        interval: Duration,
+    ) -> Self {
+        let state = Arc::new(Mutex::new(RefreshState::default()));
+        let stopped = Arc::new(AtomicBool::new(false));
+        let refresh_state = state.clone();
+        let refresh_stopped = stopped.clone();
+        http::spawn(async move {
+            let mut etag = None;
+            while !refresh_stopped.load(Ordering::Relaxed) {
+                let result = http::get_if_none_match(&http, &path, etag.as_deref()).await;
+                if refresh_stopped.load(Ordering::Relaxed) {
+                    return;
+                }
+                {
+                    let mut state = refresh_state.lock().unwrap();
+                    match result {
+                        Ok(Conditional::Modified::<Vec<Score>> {
+                            value: mut scores,
+                            etag: new_etag,
+                        }) => {
+                            etag = new_etag;
+                            order.rank(&mut scores);
+                            anonymizer.apply(&mut scores);
+                            let changed = state.scores.as_ref().is_none_or(|previous| {
+                                !LeaderboardDiff::new(previous, &scores).is_empty()
+                            });
+                            if let Some(listener) = &listener {
+                                (listener.0)(&LeaderboardEvent::LeaderboardRefreshed(
+                                    scores.clone(),
+                                ));
+                            }
+                            state.error = None;
+                            if changed {
+                                state.scores = Some(scores);
+                                state.changed = true;
+                                if let Some(waker) = state.waker.take() {
+                                    waker.wake();
+                                }
+                            }
+                        }
+                        Ok(Conditional::NotModified) => state.error = None,
+                        Err(error) => {
+                            if let Some(listener) = &listener {
+                                (listener.0)(&LeaderboardEvent::Failed {
+                                    operation: Operation::RefreshLeaderboard,
+                                    error: error.clone(),
+                                });
+                            }
+                            state.error = Some(error);
+                        }
+                    }
+                }
+                http::sleep(interval).await;
+            }
+        });
+        Self { state, stopped }
+    }
+
+    /// The latest leaderboard received, ranked. `None` until the first refresh completes.
+    pub fn latest(&self) -> Option<Vec<Score>> {
+        self.state.lock().unwrap().scores.clone()
+    }
+
+    /// The latest leaderboard received, if it changed since the last time it was taken with
+    /// this method or [`Self::changed`]. This can be called once per frame.
+    pub fn take_changes(&mut self) -> Option<Vec<Score>> {
+        let mut state = self.state.lock().unwrap();
+        if !state.changed {
+            return None;
+        }
+        state.changed = false;
+        state.scores.clone()
+    }
+
+    /// Wait until the leaderboard changes, and return it.
+    pub async fn changed(&mut self) -> Vec<Score> {
+        std::future::poll_fn(|cx| {
+            let mut state = self.state.lock().unwrap();
+            match (state.changed, &state.scores) {
+                (true, Some(scores)) => {
+                    let scores = scores.clone();
+                    state.changed = false;
+                    Poll::Ready(scores)
+                }
+                _ => {
+                    state.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+
+    /// Why the last refresh failed, if it did. Refreshing continues after a failure.
+    pub fn last_error(&self) -> Option<JornetError> {
+        self.state.lock().unwrap().error.clone()
+    }
+}
+
+impl Drop for AutoRefresh {
+    fn drop(&mut self) {
+        // the refresh stops after its current request or wait
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,39 @@
+use serde::Deserialize;
+
+use crate::{now_secs, timestamp};
+
+/// A season of a leaderboard. Scores are tagged with the season they were sent in, so that the
+/// leaderboard can be reset each season while past seasons can still be queried.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Season {
+    /// ID of the season, used to tag and query scores.
+    pub id: String,
+    /// Human-readable name of the season, if it has one.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Start of the season, in RFC 3339 format.
+    pub starts_at: String,
+    /// End of the season, in RFC 3339 format, if it's known.
+    #[serde(default)]
+    pub ends_at: Option<String>,
+}
+
+impl Season {
+    /// Start of the season, in seconds since the Unix epoch.
+    pub fn start_unix_timestamp(&self) -> Option<i64> {
+        timestamp::parse_rfc3339(&self.starts_at)
+    }
+
+    /// End of the season, in seconds since the Unix epoch.
+    pub fn end_unix_timestamp(&self) -> Option<i64> {
+        self.ends_at.as_deref().and_then(timestamp::parse_rfc3339)
+    }
+
+    /// Whether the season is running now, according to the local clock.
+    pub fn is_current(&self) -> bool {
+        let now = now_secs() as i64;
+        self.start_unix_timestamp()
+            .is_some_and(|start| start <= now)
+            && self.end_unix_timestamp().is_none_or(|end| now < end)
+    }
+}
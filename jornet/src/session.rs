@@ -0,0 +1,52 @@
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::{now_secs, Player};
+
+/// How long before it expires a session is renewed, in seconds, so that it doesn't expire while
+/// a score is being sent.
+const RENEW_BEFORE: u64 = 60;
+
+/// A short-lived credential of a player, minted with the key of the player and used to sign
+/// scores instead of it. See
+/// [`LeaderboardBuilder::session_tokens`](crate::LeaderboardBuilder::session_tokens).
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct Session {
+    /// ID of the session, sent with the scores it signs.
+    pub(crate) id: Uuid,
+    /// Key used to sign scores during the session.
+    pub(crate) secret: Uuid,
+    /// When the session expires, in seconds since the Unix epoch.
+    pub(crate) expires_at: u64,
+}
+
+impl Session {
+    /// Whether the session must be renewed before signing a score.
+    pub(crate) fn is_expiring(&self) -> bool {
+        now_secs() + RENEW_BEFORE >= self.expires_at
+    }
+}
+
+/// Request to start a session, signed by the player.
+#[derive(Serialize)]
+pub(crate) struct SessionRequest {
+    pub timestamp: u64,
+    pub k: String,
+}
+
+impl SessionRequest {
+    pub(crate) fn new(player: &Player) -> Self {
+        let timestamp = now_secs();
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(player.key.as_bytes()).unwrap();
+        mac.update(&timestamp.to_le_bytes());
+        mac.update(player.id.as_bytes());
+        // not length-prefixed, so that the signature can't be mistaken for a deletion or a rename
+        mac.update(b"session");
+
+        let hmac = hex::encode(&mac.finalize().into_bytes()[..]);
+        Self { timestamp, k: hmac }
+    }
+}
@@ -0,0 +1,43 @@
+use std::{borrow::Cow, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{now_secs, storage, JornetError, Score};
+
+/// Last leaderboard received, persisted so that it can be shown before the first request
+/// completes.
+pub(crate) struct SnapshotCache {
+    location: String,
+    ttl: Duration,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot<'a> {
+    /// When the snapshot was saved, in seconds since the Unix epoch.
+    saved_at: u64,
+    scores: Cow<'a, [Score]>,
+}
+
+impl SnapshotCache {
+    pub(crate) fn new(location: String, ttl: Duration) -> Self {
+        Self { location, ttl }
+    }
+
+    /// Read the snapshot, unless it's older than the TTL. A snapshot that can't be read is
+    /// ignored.
+    pub(crate) fn load(&self) -> Option<Vec<Score>> {
+        let snapshot: Snapshot =
+            storage::read(&self.location).and_then(|data| serde_json::from_str(&data).ok())?;
+        let age = now_secs().saturating_sub(snapshot.saved_at);
+        (age <= self.ttl.as_secs()).then(|| snapshot.scores.into_owned())
+    }
+
+    pub(crate) fn save(&self, scores: &[Score]) -> Result<(), JornetError> {
+        let snapshot = Snapshot {
+            saved_at: now_secs(),
+            scores: Cow::Borrowed(scores),
+        };
+        // serializing scores - can't fail
+        storage::write(&self.location, &serde_json::to_string(&snapshot).unwrap())
+    }
+}
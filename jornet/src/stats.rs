@@ -0,0 +1,182 @@
+use serde::Deserialize;
+
+use crate::{Score, ScoreOrder, ScoreValue};
+
+/// Number of buckets of the histogram computed from scores.
+const HISTOGRAM_BUCKETS: usize = 10;
+
+/// Statistics about the scores of a leaderboard, computed by the server with
+/// [`Leaderboard::get_stats`](crate::Leaderboard::get_stats) or from scores already received
+/// with [`LeaderboardStats::from`].
+#[derive(Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct LeaderboardStats {
+    /// Number of scores.
+    pub count: usize,
+    /// Lowest score.
+    pub min: f64,
+    /// Highest score.
+    pub max: f64,
+    /// Average of the scores.
+    pub mean: f64,
+    /// Median of the scores.
+    pub median: f64,
+    /// Score at each percentile from 0 to 100, from the lowest. Empty if there are no scores.
+    #[serde(default)]
+    pub percentiles: Vec<f64>,
+    /// Distribution of the scores, from the lowest.
+    #[serde(default)]
+    pub histogram: Vec<HistogramBucket>,
+}
+
+/// A range of scores in a [`LeaderboardStats::histogram`].
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct HistogramBucket {
+    /// Lowest score of the range, included.
+    pub from: f64,
+    /// Highest score of the range, excluded except for the last bucket.
+    pub to: f64,
+    /// Number of scores in the range.
+    pub count: usize,
+}
+
+/// Statistics about the scores of a player in a leaderboard, computed by the server with
+/// [`Leaderboard::get_player_stats`](crate::Leaderboard::get_player_stats) or from scores
+/// already received with [`PlayerStats::from_scores`].
+#[derive(Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct PlayerStats {
+    /// Number of scores sent by the player.
+    #[serde(alias = "runs")]
+    pub count: usize,
+    /// Best score of the player, according to the order of the leaderboard, or `None` if the
+    /// player has no scores.
+    #[serde(default)]
+    pub best: Option<f64>,
+    /// Average of the scores of the player, or `None` if the player has no scores.
+    #[serde(default, alias = "average")]
+    pub mean: Option<f64>,
+    /// Rank of the best score of the player, if known.
+    #[serde(default)]
+    pub rank: Option<u32>,
+}
+
+impl PlayerStats {
+    /// Compute the statistics of the scores of a player, using their ranks if they are set.
+    pub fn from_scores(scores: &[Score], order: ScoreOrder) -> Self {
+        let best = scores
+            .iter()
+            .min_by(|a, b| order.compare(a, b))
+            .map(|score| score.value().as_f64());
+        let total: f64 = scores.iter().map(|score| score.value().as_f64()).sum();
+        Self {
+            count: scores.len(),
+            best,
+            mean: best.map(|_| total / scores.len() as f64),
+            rank: scores.iter().filter_map(|score| score.rank).min(),
+        }
+    }
+}
+
+/// Where a score would rank in a leaderboard. See
+/// [`Leaderboard::get_percentile`](crate::Leaderboard::get_percentile).
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct ScorePlacement {
+    /// Rank the score would have, starting at 1.
+    pub rank: u32,
+    /// Number of scores in the leaderboard.
+    pub score_count: u64,
+    /// Fraction of the scores that the score is better than, between 0 and 1.
+    pub better_than: f64,
+}
+
+impl LeaderboardStats {
+    /// The score at percentile `percent`, from 0 for the lowest to 100 for the highest,
+    /// interpolated between the known percentiles. Returns `None` if there are no scores.
+    pub fn percentile(&self, percent: f64) -> Option<f64> {
+        let last = self.percentiles.len().checked_sub(1)?;
+        let position = (percent.clamp(0.0, 100.0) / 100.0) * last as f64;
+        let index = (position.floor() as usize).min(last);
+        let next = (index + 1).min(last);
+        let weight = position - index as f64;
+        Some(self.percentiles[index] * (1.0 - weight) + self.percentiles[next] * weight)
+    }
+
+    /// Estimate the fraction of scores that `value` is better than, between 0 and 1, according
+    /// to the order of the leaderboard. This can be shown as "you're better than 87% of
+    /// players". Returns `None` if there are no scores.
+    pub fn better_than(&self, value: ScoreValue, order: ScoreOrder) -> Option<f64> {
+        let value = value.as_f64();
+        let last = self.percentiles.len().checked_sub(1)?;
+        let below = if value < self.percentiles[0] {
+            0.0
+        } else if value >= self.percentiles[last] {
+            1.0
+        } else {
+            // the percentiles are sorted, and `value` is between the first and the last
+            let index = self
+                .percentiles
+                .windows(2)
+                .position(|pair| value < pair[1])
+                .unwrap_or(last - 1);
+            let (low, high) = (self.percentiles[index], self.percentiles[index + 1]);
+            let weight = if high > low {
+                (value - low) / (high - low)
+            } else {
+                0.0
+            };
+            (index as f64 + weight) / last as f64
+        };
+        Some(match order {
+            ScoreOrder::HigherIsBetter => below,
+            ScoreOrder::LowerIsBetter => 1.0 - below,
+        })
+    }
+}
+
+impl From<&[Score]> for LeaderboardStats {
+    fn from(scores: &[Score]) -> Self {
+        let mut values: Vec<f64> = scores.iter().map(|score| score.value().as_f64()).collect();
+        values.sort_by(f64::total_cmp);
+        let (Some(&min), Some(&max)) = (values.first(), values.last()) else {
+            return Self::default();
+        };
+        let at = |percent: f64| {
+            let position = percent / 100.0 * (values.len() - 1) as f64;
+            let index = position.floor() as usize;
+            let next = (index + 1).min(values.len() - 1);
+            let weight = position - index as f64;
+            values[index] * (1.0 - weight) + values[next] * weight
+        };
+        let percentiles: Vec<f64> = (0..=100).map(|percent| at(percent as f64)).collect();
+
+        let width = (max - min) / HISTOGRAM_BUCKETS as f64;
+        let mut histogram: Vec<HistogramBucket> = (0..HISTOGRAM_BUCKETS)
+            .map(|bucket| HistogramBucket {
+                from: min + width * bucket as f64,
+                to: min + width * (bucket + 1) as f64,
+                count: 0,
+            })
+            .collect();
+        for value in &values {
+            let bucket = if width > 0.0 {
+                (((value - min) / width) as usize).min(HISTOGRAM_BUCKETS - 1)
+            } else {
+                0
+            };
+            histogram[bucket].count += 1;
+        }
+        // all the scores are equal, a single bucket holds them
+        if width == 0.0 {
+            histogram.truncate(1);
+        }
+
+        Self {
+            count: values.len(),
+            min,
+            max,
+            mean: values.iter().sum::<f64>() / values.len() as f64,
+            median: percentiles[50],
+            percentiles,
+            histogram,
+        }
+    }
+}
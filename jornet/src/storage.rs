@@ -0,0 +1,66 @@
+//! Persistence of small values, in files on native targets and in `localStorage` on wasm.
+
+pub(crate) use platform::{read, remove, write};
+
+#[cfg(not(target_arch = "wasm32"))]
+mod platform {
+    use crate::JornetError;
+
+    /// Read the file at `path`, if it exists.
+    pub(crate) fn read(path: &str) -> Option<String> {
+        std::fs::read_to_string(path).ok()
+    }
+
+    /// Replace the content of the file at `path`. The content is written to a temporary file
+    /// first, so that the file is never left half-written.
+    pub(crate) fn write(path: &str, data: &str) -> Result<(), JornetError> {
+        let temporary = format!("{}.tmp", path);
+        std::fs::write(&temporary, data)
+            .and_then(|_| std::fs::rename(&temporary, path))
+            .map_err(|error| JornetError::Storage(error.to_string()))
+    }
+
+    /// Remove the file at `path`, if it exists.
+    pub(crate) fn remove(path: &str) -> Result<(), JornetError> {
+        match std::fs::remove_file(path) {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => {
+                Err(JornetError::Storage(error.to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod platform {
+    use crate::JornetError;
+
+    fn local_storage() -> Result<web_sys::Storage, JornetError> {
+        // getting the window - can't fail
+        web_sys::window()
+            .unwrap()
+            .local_storage()
+            .ok()
+            .flatten()
+            .ok_or_else(|| JornetError::Storage("localStorage is not available".to_string()))
+    }
+
+    /// Read the item `key` of `localStorage`, if it exists.
+    pub(crate) fn read(key: &str) -> Option<String> {
+        local_storage().ok()?.get_item(key).ok().flatten()
+    }
+
+    /// Replace the item `key` of `localStorage`.
+    pub(crate) fn write(key: &str, data: &str) -> Result<(), JornetError> {
+        local_storage()?
+            .set_item(key, data)
+            .map_err(|error| JornetError::Storage(format!("{:?}", error)))
+    }
+
+    /// Remove the item `key` of `localStorage`, if it exists.
+    pub(crate) fn remove(key: &str) -> Result<(), JornetError> {
+        local_storage()?
+            .remove_item(key)
+            .map_err(|error| JornetError::Storage(format!("{:?}", error)))
+    }
+}
@@ -0,0 +1,98 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    task::{Poll, Waker},
+};
+
+use crate::{
+    anonymize::Anonymizer,
+    http::{self, EventSource, HttpConfig, StreamUpdate},
+    Score,
+};
+
+/// Scores sent to a leaderboard, received live from the server as server-sent events. See
+/// [`Leaderboard::stream_scores`](crate::Leaderboard::stream_scores).
+///
+/// The stream reconnects by itself when it's interrupted, and resumes after the last score
+/// received so that none are missed. It's closed when dropped.
+pub struct ScoreStream {
+    state: Arc<Mutex<StreamState>>,
+    _source: EventSource,
+}
+
+#[derive(Default)]
+struct StreamState {
+    scores: VecDeque<Score>,
+    connected: bool,
+    waker: Option<Waker>,
+}
+
+impl ScoreStream {
+    pub(crate) fn new(config: &HttpConfig, path: &str, anonymizer: Anonymizer) -> Self {
+        let state = Arc::new(Mutex::new(StreamState::default()));
+        let stream_state = state.clone();
+        let source = http::event_source(
+            config,
+            path,
+            Arc::new(move |update| {
+                let mut state = stream_state.lock().unwrap();
+                match update {
+                    StreamUpdate::Connected => state.connected = true,
+                    StreamUpdate::Disconnected(_error) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(error = %_error, "score stream interrupted");
+                        state.connected = false;
+                    }
+                    StreamUpdate::Event(event)
+                        if event.event == "score" || event.event == "message" =>
+                    {
+                        match serde_json::from_str(&event.data) {
+                            Ok(mut score) => {
+                                anonymizer.apply_one(&mut score);
+                                state.scores.push_back(score);
+                                if let Some(waker) = state.waker.take() {
+                                    waker.wake();
+                                }
+                            }
+                            Err(_error) => {
+                                #[cfg(feature = "tracing")]
+                                tracing::warn!(error = %_error, "ignoring invalid score in stream");
+                            }
+                        }
+                    }
+                    StreamUpdate::Event(_) => (),
+                }
+            }),
+        );
+        Self {
+            state,
+            _source: source,
+        }
+    }
+
+    /// Wait for the next score sent to the leaderboard.
+    pub async fn next(&mut self) -> Score {
+        std::future::poll_fn(|cx| {
+            let mut state = self.state.lock().unwrap();
+            match state.scores.pop_front() {
+                Some(score) => Poll::Ready(score),
+                None => {
+                    state.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+
+    /// Get the next score already received, without waiting. This can be called once per frame
+    /// by games that don't run an async runtime.
+    pub fn try_next(&mut self) -> Option<Score> {
+        self.state.lock().unwrap().scores.pop_front()
+    }
+
+    /// Whether the stream is currently connected to the server.
+    pub fn is_connected(&self) -> bool {
+        self.state.lock().unwrap().connected
+    }
+}
@@ -0,0 +1,199 @@
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{JornetError, ScoreValue};
+
+/// Check performed on scores before they are sent.
+pub(crate) type Validator = Arc<dyn Fn(&ScoreSubmission) -> Result<(), String> + Send + Sync>;
+
+/// A score to send with [`Leaderboard::submit`](crate::Leaderboard::submit), with everything
+/// that can be sent along with it.
+///
+/// ```no_run
+/// # async fn run(leaderboard: &jornet::Leaderboard) -> Result<(), jornet::JornetError> {
+/// use jornet::ScoreSubmission;
+///
+/// leaderboard
+///     .submit(ScoreSubmission::new(12.5).meta("level 3").at(1_650_000_000))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScoreSubmission {
+    pub(crate) value: ScoreValue,
+    pub(crate) meta: Option<String>,
+    pub(crate) dimensions: BTreeMap<String, f64>,
+    pub(crate) timestamp: Option<u64>,
+    pub(crate) idempotency_key: Option<Uuid>,
+    pub(crate) group: Option<String>,
+    pub(crate) season: Option<String>,
+    pub(crate) team: Option<String>,
+    pub(crate) player: Option<Uuid>,
+}
+
+impl ScoreSubmission {
+    /// Submit a float score.
+    pub fn new(score: f32) -> Self {
+        Self::from_value(ScoreValue::Float(score))
+    }
+
+    /// Submit an integer score, that doesn't lose precision above 2^24.
+    pub fn integer(score: i64) -> Self {
+        Self::from_value(ScoreValue::Integer(score))
+    }
+
+    /// Submit a duration, like a completion time. It's sent as an integer number of
+    /// milliseconds, so that all games using durations can share leaderboards.
+    pub fn duration(duration: Duration) -> Self {
+        Self::integer(duration.as_millis().min(i64::MAX as u128) as i64)
+    }
+
+    fn from_value(value: ScoreValue) -> Self {
+        Self {
+            value,
+            meta: None,
+            dimensions: BTreeMap::new(),
+            timestamp: None,
+            idempotency_key: None,
+            group: None,
+            season: None,
+            team: None,
+            player: None,
+        }
+    }
+
+    /// The value of the score.
+    pub fn value(&self) -> ScoreValue {
+        self.value
+    }
+
+    /// The metadata of the score, if set.
+    pub fn get_meta(&self) -> Option<&str> {
+        self.meta.as_deref()
+    }
+
+    /// A named secondary value of the score, if set.
+    pub fn get_dimension(&self, name: &str) -> Option<f64> {
+        self.dimensions.get(name).copied()
+    }
+
+    /// When the score was achieved, if set.
+    pub fn timestamp(&self) -> Option<u64> {
+        self.timestamp
+    }
+
+    /// The group of the score, if set.
+    pub fn get_group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    /// The season of the score, if set.
+    pub fn get_season(&self) -> Option<&str> {
+        self.season.as_deref()
+    }
+
+    /// The team of the player, if set.
+    pub fn get_team(&self) -> Option<&str> {
+        self.team.as_deref()
+    }
+
+    /// The ID of the local player sending the score, if set.
+    pub fn get_player(&self) -> Option<Uuid> {
+        self.player
+    }
+
+    /// Set the metadata of the score, information about the game, victory conditions, ...
+    pub fn meta(mut self, meta: &str) -> Self {
+        self.meta = Some(meta.to_string());
+        self
+    }
+
+    /// Set the metadata of the score, serialized to JSON. It can be read back with
+    /// [`Score::meta_as`](crate::Score::meta_as).
+    ///
+    /// Keys of maps are sorted, so that the same metadata is always sent and signed as the same
+    /// string.
+    pub fn meta_typed(mut self, meta: impl Serialize) -> Result<Self, JornetError> {
+        self.meta = Some(canonical_json(meta)?);
+        Ok(self)
+    }
+
+    /// Add a named secondary value to the score. See
+    /// [`Leaderboard::send_score_with_dimensions`](crate::Leaderboard::send_score_with_dimensions).
+    pub fn dimension(mut self, name: &str, value: f64) -> Self {
+        self.dimensions.insert(name.to_string(), value);
+        self
+    }
+
+    /// Set when the score was achieved, in seconds since the Unix epoch. By default, this is
+    /// when the score is sent. The timestamp is signed with the score.
+    pub fn at(mut self, timestamp: u64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Set the idempotency key of the submission, so that submitting the same score again is
+    /// ignored by the server. See
+    /// [`LeaderboardBuilder::idempotency_keys`](crate::LeaderboardBuilder::idempotency_keys).
+    pub fn idempotency_key(mut self, key: Uuid) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Send the score to a sub-board of the leaderboard, like `level-42`, so that games with
+    /// many levels don't need a leaderboard for each. See
+    /// [`Leaderboard::send_score_for`](crate::Leaderboard::send_score_for).
+    pub fn group(mut self, group: &str) -> Self {
+        self.group = Some(group.to_string());
+        self
+    }
+
+    /// Tag the score with the ID of a [`Season`](crate::Season), usually the current one. See
+    /// [`Leaderboard::get_current_season`](crate::Leaderboard::get_current_season).
+    pub fn season(mut self, season: &str) -> Self {
+        self.season = Some(season.to_string());
+        self
+    }
+
+    /// Set the team, guild or clan of the player, so that the score counts towards the team.
+    /// See [`Leaderboard::get_team_leaderboard`](crate::Leaderboard::get_team_leaderboard).
+    pub fn team(mut self, team: &str) -> Self {
+        self.team = Some(team.to_string());
+        self
+    }
+
+    /// Send the score as one of the local players of the leaderboard, instead of the current
+    /// player. See [`Leaderboard::add_local_player`](crate::Leaderboard::add_local_player).
+    pub fn by_player(mut self, id: Uuid) -> Self {
+        self.player = Some(id);
+        self
+    }
+}
+
+/// Serialize a value to JSON, with the keys of maps sorted.
+fn canonical_json(value: impl Serialize) -> Result<String, JornetError> {
+    fn sort_keys(value: Value) -> Value {
+        match value {
+            Value::Object(map) => {
+                let mut entries: Vec<_> = map.into_iter().collect();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (key, sort_keys(value)))
+                        .collect(),
+                )
+            }
+            Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+            value => value,
+        }
+    }
+
+    let value = serde_json::to_value(value)
+        .map_err(|error| JornetError::Serialization(error.to_string()))?;
+    Ok(sort_keys(value).to_string())
+}
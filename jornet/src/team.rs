@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::{Score, ScoreOrder};
+
+/// How the scores of the members of a team are combined into the score of the team. Only the
+/// best score of each member is counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Aggregation {
+    /// Sum of the scores of the members.
+    #[default]
+    Sum,
+    /// Best score of the members.
+    Best,
+    /// Average of the scores of the members.
+    Average,
+}
+
+/// Score of a team in a team leaderboard. See
+/// [`Leaderboard::get_team_leaderboard`](crate::Leaderboard::get_team_leaderboard).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TeamScore {
+    /// ID of the team.
+    pub team: String,
+    /// Combined score of the members of the team.
+    pub score: f64,
+    /// Number of members of the team with a score.
+    pub members: usize,
+    /// Rank of the team, starting at 1.
+    #[serde(default)]
+    pub rank: u32,
+}
+
+impl Aggregation {
+    /// Name of the aggregation, as sent to the server.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Aggregation::Sum => "sum",
+            Aggregation::Best => "best",
+            Aggregation::Average => "average",
+        }
+    }
+
+    /// Combine scores already received into the scores of their teams, ranked and sorted from
+    /// the best. Scores without a team are ignored.
+    pub fn aggregate(&self, scores: Vec<Score>, order: ScoreOrder) -> Vec<TeamScore> {
+        let mut teams: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+        for score in order.best_per_player(scores) {
+            if let Some(team) = &score.team {
+                teams
+                    .entry(team.clone())
+                    .or_default()
+                    .push(score.value().as_f64());
+            }
+        }
+
+        let compare = |a: f64, b: f64| match order {
+            ScoreOrder::HigherIsBetter => b.total_cmp(&a),
+            ScoreOrder::LowerIsBetter => a.total_cmp(&b),
+        };
+        let mut teams: Vec<TeamScore> = teams
+            .into_iter()
+            .map(|(team, values)| TeamScore {
+                score: match self {
+                    Aggregation::Sum => values.iter().sum(),
+                    // members have at least one score - can't fail
+                    Aggregation::Best => values
+                        .iter()
+                        .copied()
+                        .min_by(|a, b| compare(*a, *b))
+                        .unwrap(),
+                    Aggregation::Average => values.iter().sum::<f64>() / values.len() as f64,
+                },
+                members: values.len(),
+                team,
+                rank: 0,
+            })
+            .collect();
+        teams.sort_by(|a, b| compare(a.score, b.score));
+        for index in 0..teams.len() {
+            // equal scores share the same rank
+            teams[index].rank = if index > 0 && teams[index - 1].score == teams[index].score {
+                teams[index - 1].rank
+            } else {
+                index as u32 + 1
+            };
+        }
+        teams
+    }
+}
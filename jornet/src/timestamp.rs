@@ -0,0 +1,82 @@
+//! Conversions between Unix timestamps and dates, without depending on a date library.
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Parse an RFC 3339 timestamp, like `2022-05-01T12:00:00.123Z`, into seconds since the Unix
+/// epoch.
+pub(crate) fn parse_rfc3339(timestamp: &str) -> Option<i64> {
+    let bytes = timestamp.as_bytes();
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || bytes[13] != b':' {
+        return None;
+    }
+    let number =
+        |range: std::ops::Range<usize>| -> Option<i64> { timestamp.get(range)?.parse().ok() };
+    let days = days_from_civil(number(0..4)?, number(5..7)?, number(8..10)?);
+    let seconds = number(11..13)? * 3600 + number(14..16)? * 60 + number(17..19)?;
+    // skip fractional seconds
+    let offset_start = timestamp[19..]
+        .find(|c: char| c != '.' && !c.is_ascii_digit())
+        .map(|index| index + 19)?;
+    let offset = match &timestamp[offset_start..] {
+        "Z" | "z" => 0,
+        offset if offset.len() == 6 => {
+            let sign = match offset.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            sign * (number(offset_start + 1..offset_start + 3)? * 3600
+                + number(offset_start + 4..offset_start + 6)? * 60)
+        }
+        _ => return None,
+    };
+    Some(days * SECONDS_PER_DAY + seconds - offset)
+}
+
+/// Start of the UTC day containing `timestamp`.
+pub(crate) fn start_of_day(timestamp: i64) -> i64 {
+    timestamp.div_euclid(SECONDS_PER_DAY) * SECONDS_PER_DAY
+}
+
+/// Start of the UTC week, starting on Monday, containing `timestamp`.
+pub(crate) fn start_of_week(timestamp: i64) -> i64 {
+    let days = timestamp.div_euclid(SECONDS_PER_DAY);
+    // the Unix epoch was a Thursday
+    let weekday = (days + 3).rem_euclid(7);
+    (days - weekday) * SECONDS_PER_DAY
+}
+
+/// Start of the UTC month containing `timestamp`.
+pub(crate) fn start_of_month(timestamp: i64) -> i64 {
+    let (year, month, _) = civil_from_days(timestamp.div_euclid(SECONDS_PER_DAY));
+    days_from_civil(year, month, 1) * SECONDS_PER_DAY
+}
+
+/// Number of days since the Unix epoch of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date in the proleptic Gregorian calendar of a number of days since the Unix epoch.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
@@ -0,0 +1,68 @@
+use crate::{Player, Score, ScoreOrder};
+
+/// Scores of a leaderboard, ranked and sorted from the best.
+#[derive(Debug, Clone)]
+pub struct LeaderboardView {
+    scores: Vec<Score>,
+}
+
+impl LeaderboardView {
+    /// Rank and sort scores according to the order of their leaderboard. Scores that already
+    /// have a rank keep it.
+    pub fn new(mut scores: Vec<Score>, order: ScoreOrder) -> Self {
+        order.rank(&mut scores);
+        order.sort(&mut scores);
+        Self { scores }
+    }
+
+    /// Iterate over scores with their rank, from the best.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &Score)> {
+        self.scores
+            .iter()
+            .enumerate()
+            .map(|(index, score)| (score.rank.unwrap_or(index as u32 + 1), score))
+    }
+
+    /// Number of scores.
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    /// Whether there are no scores.
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+
+    /// Score at a position, starting at 0 for the best.
+    pub fn get(&self, position: usize) -> Option<&Score> {
+        self.scores.get(position)
+    }
+
+    /// Whether the score at a position shares its rank with other scores.
+    pub fn is_tied(&self, position: usize) -> bool {
+        let Some(rank) = self.get(position).and_then(|score| score.rank) else {
+            return false;
+        };
+        let same_rank = |other: usize| self.get(other).and_then(|score| score.rank) == Some(rank);
+        (position > 0 && same_rank(position - 1)) || same_rank(position + 1)
+    }
+
+    /// Position of the best score of a player, starting at 0 for the best. Players are
+    /// identified by their ID if the server exposes it, otherwise by their name.
+    pub fn position_of(&self, player: &Player) -> Option<usize> {
+        self.scores.iter().position(|score| match score.player_id {
+            Some(id) => id == player.id,
+            None => score.player == player.name,
+        })
+    }
+
+    /// The scores, sorted from the best.
+    pub fn scores(&self) -> &[Score] {
+        &self.scores
+    }
+
+    /// Take the scores, sorted from the best.
+    pub fn into_scores(self) -> Vec<Score> {
+        self.scores
+    }
+}
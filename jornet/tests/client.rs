@@ -0,0 +1,58 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use helper::server::{block_on, serve};
+use jornet::{JornetClient, Leaderboard, NameGenerator};
+use uuid::Uuid;
+
+mod helper;
+
+/// Server creating players with the name they were sent, and renaming them.
+fn players_server() -> String {
+    serve(|request, body| {
+        let body: serde_json::Value = serde_json::from_str(body).unwrap_or_default();
+        if request.starts_with("POST /api/v1/players") {
+            serde_json::json!({
+                "id": Uuid::new_v4(),
+                "key": Uuid::new_v4(),
+                "name": body["name"],
+            })
+            .to_string()
+        } else {
+            "null".to_string()
+        }
+    })
+}
+
+fn player_names(client: &JornetClient) -> Vec<Option<String>> {
+    client
+        .leaderboards()
+        .map(|leaderboard| leaderboard.get_player().map(|player| player.name.clone()))
+        .collect()
+}
+
+#[test]
+fn leaderboards_share_the_player() {
+    let host = players_server();
+    let mut client = JornetClient::new(Some(host.clone()));
+    client.insert_leaderboard(
+        Leaderboard::builder(Uuid::new_v4(), Uuid::new_v4())
+            .host(&host)
+            .name_generator(NameGenerator::from_words(&[&["Generated"], &["Name"]], " "))
+            .build(),
+    );
+    client.add_leaderboard(Uuid::new_v4(), Uuid::new_v4());
+
+    let player = block_on(client.create_player(None)).unwrap().clone();
+    assert_eq!(player.name, "Generated Name");
+    client.add_leaderboard(Uuid::new_v4(), Uuid::new_v4());
+    assert_eq!(player_names(&client), vec![Some(player.name.clone()); 3]);
+    assert!(client
+        .leaderboards()
+        .all(|leaderboard| leaderboard.get_player().unwrap().id == player.id));
+
+    block_on(client.rename_player("Renamed")).unwrap();
+    assert_eq!(player_names(&client), vec![Some("Renamed".to_string()); 3]);
+
+    block_on(client.delete_player()).unwrap();
+    assert_eq!(player_names(&client), vec![None; 3]);
+}
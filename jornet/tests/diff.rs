@@ -0,0 +1,41 @@
+use jornet::{LeaderboardDiff, Score, ScoreOrder};
+
+mod helper;
+
+fn leaderboard(scores: &[(&str, f32)]) -> Vec<Score> {
+    let mut scores: Vec<Score> = scores
+        .iter()
+        .map(|(player, score)| helper::score(player, *score))
+        .collect();
+    ScoreOrder::default().rank(&mut scores);
+    ScoreOrder::default().sort(&mut scores);
+    scores
+}
+
+#[test]
+fn new_scores_and_rank_changes() {
+    let previous = leaderboard(&[("a", 3.0), ("b", 2.0), ("c", 1.0)]);
+    let current = leaderboard(&[("a", 3.0), ("b", 2.0), ("c", 1.0), ("c", 2.5)]);
+
+    let diff = LeaderboardDiff::new(&previous, &current);
+
+    assert_eq!(diff.inserted.len(), 1);
+    assert!(diff.is_new(&current[1]));
+    assert!(!diff.is_new(&current[0]));
+    assert!(diff.removed.is_empty());
+    let changes: Vec<_> = diff
+        .rank_changes
+        .iter()
+        .map(|change| {
+            (
+                change.score.player.as_str(),
+                change.previous_rank,
+                change.rank,
+            )
+        })
+        .collect();
+    assert_eq!(changes, vec![("b", 2, 3), ("c", 3, 4)]);
+    assert!(diff.player_moved("c"));
+    assert_eq!(diff.player_ranks("c"), (Some(3), Some(2)));
+    assert!(!diff.player_moved("a"));
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "export")]
+
+use helper::score_with;
+use jornet::LeaderboardExport;
+
+mod helper;
+
+#[test]
+fn csv_with_dimensions_and_escaping() {
+    let scores = vec![
+        score_with(serde_json::json!({
+            "score": 12.5,
+            "player": "alice",
+            "meta": "level 1, hard",
+            "dimensions": {"kills": 3.0},
+            "rank": 1,
+        })),
+        score_with(serde_json::json!({
+            "score": 10.0,
+            "score_i64": 10,
+            "player": "=cmd()",
+            "timestamp": "2022-05-01T13:00:00Z",
+            "rank": 2,
+        })),
+    ];
+    assert_eq!(
+        LeaderboardExport::new(&scores).to_csv(),
+        "rank,player,score,timestamp,meta,kills\r\n\
+         1,alice,12.5,2022-05-01T12:00:00Z,\"level 1, hard\",3\r\n\
+         2,'=cmd(),10,2022-05-01T13:00:00Z,,\r\n"
+    );
+}
@@ -0,0 +1,111 @@
+// each test uses only some of the helpers
+#![allow(dead_code)]
+
+use jornet::{Player, Score};
+use uuid::Uuid;
+
+/// Score with the given `fields` of its JSON representation, the others being those of a score
+/// of `1.0` by `"player"`.
+pub fn score_with(fields: serde_json::Value) -> Score {
+    let mut score = serde_json::json!({
+        "score": 1.0,
+        "player": "player",
+        "meta": null,
+        "timestamp": "2022-05-01T12:00:00Z",
+    });
+    for (field, value) in fields.as_object().expect("fields must be an object") {
+        score[field] = value.clone();
+    }
+    serde_json::from_value(score).unwrap()
+}
+
+pub fn score(player: &str, score: f32) -> Score {
+    score_with(serde_json::json!({ "score": score, "player": player }))
+}
+
+pub fn player(name: &str) -> Player {
+    Player {
+        id: Uuid::new_v4(),
+        key: Uuid::new_v4(),
+        name: name.to_string(),
+        avatar: None,
+        region: None,
+    }
+}
+
+/// Local server to send requests to, on native targets.
+#[cfg(not(target_arch = "wasm32"))]
+pub mod server {
+    use std::{
+        future::Future,
+        io::{Read, Write},
+        net::TcpListener,
+        sync::Arc,
+        task::{Context, Poll, Wake},
+        thread::{self, Thread},
+    };
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    pub fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    /// Serve requests on a local port with `handler`, which gets the request line and body and
+    /// returns the JSON body of the response. Returns the host to send requests to.
+    pub fn serve(handler: impl Fn(&str, &str) -> String + Send + Sync + 'static) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = format!("http://{}", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request = vec![];
+                let mut buffer = [0; 1024];
+                let end_of_headers = loop {
+                    let read = stream.read(&mut buffer).unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    if let Some(end) = request.windows(4).position(|end| end == b"\r\n\r\n") {
+                        break end + 4;
+                    }
+                };
+                let headers = String::from_utf8_lossy(&request[..end_of_headers]).to_string();
+                let length = headers
+                    .lines()
+                    .find_map(|line| {
+                        line.to_ascii_lowercase()
+                            .strip_prefix("content-length:")
+                            .map(|length| length.trim().parse().unwrap())
+                    })
+                    .unwrap_or(0);
+                while request.len() < end_of_headers + length {
+                    let read = stream.read(&mut buffer).unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                }
+                let body = String::from_utf8_lossy(&request[end_of_headers..]);
+                let response = handler(headers.lines().next().unwrap(), &body);
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                )
+                .unwrap();
+            }
+        });
+        host
+    }
+}
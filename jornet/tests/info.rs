@@ -0,0 +1,44 @@
+use jornet::{LeaderboardInfo, PlayerProfile, ScoreOrder, Season};
+
+#[test]
+fn info_from_server() {
+    let info: LeaderboardInfo = serde_json::from_value(serde_json::json!({
+        "id": "00000000-0000-0000-0000-000000000001",
+        "name": "Speedrun",
+        "created_at": "2023-03-01T12:00:00Z",
+        "scores": 42,
+        "order": "lower_is_better",
+    }))
+    .unwrap();
+    assert_eq!(info.name, "Speedrun");
+    assert_eq!(info.score_count, 42);
+    assert_eq!(info.order, Some(ScoreOrder::LowerIsBetter));
+    assert_eq!(info.created_unix_timestamp(), Some(1677672000));
+}
+
+#[test]
+fn season_bounds() {
+    let season: Season = serde_json::from_value(serde_json::json!({
+        "id": "2023-03",
+        "starts_at": "2023-03-01T00:00:00Z",
+        "ends_at": "2023-04-01T00:00:00Z",
+    }))
+    .unwrap();
+    assert_eq!(season.start_unix_timestamp(), Some(1677628800));
+    assert_eq!(season.end_unix_timestamp(), Some(1680307200));
+    assert!(!season.is_current());
+}
+
+#[test]
+fn profile_from_server() {
+    let profile: PlayerProfile = serde_json::from_value(serde_json::json!({
+        "id": "00000000-0000-0000-0000-000000000002",
+        "name": "Ferris",
+        "created_at": "2023-03-01T12:00:00Z",
+        "scores": 7,
+    }))
+    .unwrap();
+    assert_eq!(profile.name, "Ferris");
+    assert_eq!(profile.score_count, 7);
+    assert_eq!(profile.joined_unix_timestamp(), Some(1677672000));
+}
@@ -0,0 +1,42 @@
+use jornet::{JornetError, NameFilter, NameGenerator};
+
+#[test]
+fn names_from_words() {
+    let generator = NameGenerator::from_words(&[&["Brave", "Sleepy"], &["Dragon"], &[]], " ");
+    for _ in 0..20 {
+        let name = generator.generate();
+        assert!(
+            name == "Brave Dragon" || name == "Sleepy Dragon",
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn names_from_closure() {
+    let generator = NameGenerator::new(|| "Ferris".to_string());
+    assert_eq!(generator.generate(), "Ferris");
+}
+
+#[test]
+fn names_for_locale() {
+    assert!(NameGenerator::locales().any(|locale| locale == "ja"));
+    let name = NameGenerator::for_locale("ja_JP.UTF-8").generate();
+    assert!(!name.contains(' ') && !name.is_ascii(), "{}", name);
+    // unsupported languages fall back to English
+    let name = NameGenerator::for_locale("tlh").generate();
+    assert!(name.contains(' ') && name.is_ascii(), "{}", name);
+}
+
+#[test]
+fn filter_names() {
+    let filter = NameFilter::from_words(&["Heck"]);
+    assert_eq!(
+        filter.check("h3_ck_Y34H"),
+        Err(JornetError::NameRejected(
+            "contains a blocked word".to_string()
+        ))
+    );
+    assert_eq!(filter.check("Ferris"), Ok(()));
+}
@@ -0,0 +1,84 @@
+use helper::{score, score_with};
+use jornet::{Score, ScoreOrder, ScoreValue};
+
+mod helper;
+
+fn players(scores: &[Score]) -> Vec<&str> {
+    scores.iter().map(|score| score.player.as_str()).collect()
+}
+
+#[test]
+fn higher_is_better_by_default() {
+    let mut scores = vec![score("a", 1.0), score("b", 3.0), score("c", 2.0)];
+    ScoreOrder::default().sort(&mut scores);
+    assert_eq!(players(&scores), vec!["b", "c", "a"]);
+}
+
+#[test]
+fn lower_is_better() {
+    let mut scores = vec![score("a", 1.0), score("b", 3.0), score("c", 2.0)];
+    ScoreOrder::LowerIsBetter.sort(&mut scores);
+    assert_eq!(players(&scores), vec!["a", "c", "b"]);
+}
+
+#[test]
+fn integers_are_compared_exactly() {
+    // both round to the same f32
+    let a = ScoreValue::Integer(100_000_001);
+    let b = ScoreValue::Integer(100_000_000);
+    assert!(ScoreOrder::HigherIsBetter.is_better(a, b));
+    assert!(ScoreOrder::LowerIsBetter.is_better(b, a));
+    assert!(!ScoreOrder::HigherIsBetter.is_better(a, a));
+}
+
+#[test]
+fn equal_scores_share_their_rank() {
+    let mut scores = vec![
+        score("a", 2.0),
+        score("b", 3.0),
+        score("c", 2.0),
+        score("d", 1.0),
+    ];
+    ScoreOrder::default().rank(&mut scores);
+    let ranks: Vec<_> = scores.iter().map(|score| score.rank.unwrap()).collect();
+    assert_eq!(ranks, vec![2, 1, 2, 4]);
+}
+
+#[test]
+fn best_per_player_keeps_consecutive_ranks() {
+    let scores = vec![
+        score("a", 5.0),
+        score("b", 4.0),
+        score("a", 3.0),
+        score("c", 2.0),
+        score("b", 1.0),
+    ];
+    let best = ScoreOrder::default().best_per_player(scores);
+    assert_eq!(players(&best), vec!["a", "b", "c"]);
+    let ranks: Vec<_> = best.iter().map(|score| score.rank.unwrap()).collect();
+    assert_eq!(ranks, vec![1, 2, 3]);
+}
+
+#[test]
+fn best_per_player_tells_players_apart_by_id() {
+    let scores = vec![
+        score_with(serde_json::json!({
+            "score": 5.0,
+            "player": "a",
+            "player_id": "00000000-0000-0000-0000-000000000001",
+        })),
+        score_with(serde_json::json!({
+            "score": 4.0,
+            "player": "a",
+            "player_id": "00000000-0000-0000-0000-000000000002",
+        })),
+        score_with(serde_json::json!({
+            "score": 3.0,
+            "player": "a",
+            "player_id": "00000000-0000-0000-0000-000000000001",
+        })),
+    ];
+    let best = ScoreOrder::default().best_per_player(scores);
+    let values: Vec<_> = best.iter().map(|score| score.score).collect();
+    assert_eq!(values, vec![5.0, 4.0]);
+}
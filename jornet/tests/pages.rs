@@ -0,0 +1,71 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::Poll,
+};
+
+use futures_core::Stream;
+use helper::server::{block_on, serve};
+use jornet::{Leaderboard, Score};
+use uuid::Uuid;
+
+mod helper;
+
+/// Serve the same `count` scores to every request, ignoring the pagination, and return the
+/// host with the number of requests received.
+fn serve_unpaginated(count: usize) -> (String, Arc<AtomicUsize>) {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let received = requests.clone();
+    let scores: Vec<_> = (0..count)
+        .map(|index| {
+            serde_json::json!({
+                "score": index as f32,
+                "player": format!("player {}", index),
+                "meta": null,
+                "timestamp": "2022-05-01T12:00:00Z",
+            })
+        })
+        .collect();
+    let scores = serde_json::to_string(&scores).unwrap();
+    let host = serve(move |_, _| {
+        // stop after a few pages if the stream doesn't end
+        match received.fetch_add(1, Ordering::SeqCst) {
+            0..=4 => scores.clone(),
+            _ => "[]".to_string(),
+        }
+    });
+    (host, requests)
+}
+
+fn collect(leaderboard: &Leaderboard) -> Vec<Score> {
+    let mut stream = leaderboard.scores_stream();
+    let mut scores = vec![];
+    block_on(std::future::poll_fn(|cx| loop {
+        match Pin::new(&mut stream).poll_next(cx) {
+            Poll::Ready(Some(score)) => scores.push(score.unwrap()),
+            Poll::Ready(None) => return Poll::Ready(()),
+            Poll::Pending => return Poll::Pending,
+        }
+    }));
+    scores
+}
+
+#[test]
+fn stream_ends_when_the_server_ignores_pagination() {
+    // exactly one full page, which can't be told apart from a paginated one by its size
+    let (host, requests) = serve_unpaginated(100);
+    let leaderboard = Leaderboard::builder(Uuid::nil(), Uuid::nil())
+        .host(&host)
+        .build();
+
+    let scores = collect(&leaderboard);
+
+    assert_eq!(scores.len(), 100);
+    assert_eq!(scores[0].player, "player 99");
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
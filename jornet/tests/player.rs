@@ -0,0 +1,45 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use jornet::Player;
+use uuid::Uuid;
+
+mod helper;
+
+#[test]
+fn save_and_load_player() {
+    let path = std::env::temp_dir().join(format!("jornet-player-{}.json", Uuid::new_v4()));
+    let path = path.to_str().unwrap();
+    assert!(Player::load_from(path).unwrap().is_none());
+
+    let player = helper::player("Ferris");
+    player.save_to(path).unwrap();
+    let loaded = Player::load_from(path).unwrap().unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(
+        (loaded.id, loaded.key, loaded.name),
+        (player.id, player.key, player.name)
+    );
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn encrypt_saved_player() {
+    use jornet::CredentialProtection;
+
+    let path = std::env::temp_dir().join(format!("jornet-player-{}.json", Uuid::new_v4()));
+    let path = path.to_str().unwrap();
+    let player = helper::player("Ferris");
+    let protection = CredentialProtection::Passphrase("correct horse".to_string());
+    player.save_protected(path, &protection).unwrap();
+    let saved = std::fs::read_to_string(path).unwrap();
+    let loaded = Player::load_protected(path, &protection).unwrap().unwrap();
+    let wrong = Player::load_protected(
+        path,
+        &CredentialProtection::Passphrase("battery staple".to_string()),
+    );
+    std::fs::remove_file(path).unwrap();
+
+    assert!(!saved.contains(&player.key.to_string()));
+    assert_eq!(loaded.key, player.key);
+    assert!(wrong.is_err());
+}
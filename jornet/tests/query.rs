@@ -0,0 +1,61 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use helper::server::{block_on, serve};
+use jornet::{Leaderboard, ScoreQuery};
+use uuid::Uuid;
+
+mod helper;
+
+fn leaderboard(scores: serde_json::Value) -> Leaderboard {
+    let scores = scores.to_string();
+    let host = serve(move |_, _| scores.clone());
+    Leaderboard::builder(Uuid::nil(), Uuid::nil())
+        .host(&host)
+        .build()
+}
+
+fn ranks(leaderboard: &Leaderboard, query: ScoreQuery) -> Vec<Option<u32>> {
+    block_on(leaderboard.query(query))
+        .unwrap()
+        .iter()
+        .map(|score| score.rank)
+        .collect()
+}
+
+#[test]
+fn ranks_sent_by_the_server_are_kept() {
+    let leaderboard = leaderboard(serde_json::json!([
+        { "score": 3.0, "player": "player", "meta": null, "timestamp": "1", "rank": 8 },
+        { "score": 5.0, "player": "player", "meta": null, "timestamp": "1", "rank": 4 },
+    ]));
+
+    let player = helper::player("player");
+    let ranks = ranks(&leaderboard, ScoreQuery::new().player(&player).top(2));
+
+    assert_eq!(ranks, vec![Some(4), Some(8)]);
+}
+
+#[test]
+fn part_of_the_leaderboard_is_not_ranked_locally() {
+    let leaderboard = leaderboard(serde_json::json!([
+        { "score": 3.0, "player": "player", "meta": null, "timestamp": "1" },
+        { "score": 5.0, "player": "player", "meta": null, "timestamp": "1" },
+    ]));
+
+    let ranks = ranks(&leaderboard, ScoreQuery::new().top(2));
+
+    assert_eq!(ranks, vec![None, None]);
+}
+
+#[test]
+fn whole_leaderboard_is_ranked_locally() {
+    let leaderboard = leaderboard(serde_json::json!([
+        { "score": 3.0, "player": "first", "meta": null, "timestamp": "1" },
+        { "score": 5.0, "player": "second", "meta": null, "timestamp": "1" },
+        { "score": 3.0, "player": "third", "meta": null, "timestamp": "1" },
+    ]));
+
+    let ranks = ranks(&leaderboard, ScoreQuery::new());
+
+    assert_eq!(ranks, vec![Some(1), Some(2), Some(2)]);
+}
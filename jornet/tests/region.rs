@@ -0,0 +1,15 @@
+use jornet::Score;
+
+mod helper;
+
+fn score(region: Option<&str>) -> Score {
+    helper::score_with(serde_json::json!({ "region": region }))
+}
+
+#[test]
+fn flag_of_region() {
+    assert_eq!(score(Some("FR")).flag().as_deref(), Some("🇫🇷"));
+    assert_eq!(score(Some("jp")).flag().as_deref(), Some("🇯🇵"));
+    assert_eq!(score(Some("FRA")).flag(), None);
+    assert_eq!(score(None).flag(), None);
+}
@@ -0,0 +1,68 @@
+use jornet::{LeaderboardStats, PlayerStats, Score, ScoreOrder, ScoreValue};
+
+mod helper;
+
+fn scores(values: &[f32]) -> Vec<Score> {
+    values
+        .iter()
+        .map(|value| helper::score("player", *value))
+        .collect()
+}
+
+#[test]
+fn stats_from_scores() {
+    let stats = LeaderboardStats::from(&scores(&[4.0, 1.0, 3.0, 2.0, 10.0])[..]);
+    assert_eq!(stats.count, 5);
+    assert_eq!(stats.min, 1.0);
+    assert_eq!(stats.max, 10.0);
+    assert_eq!(stats.mean, 4.0);
+    assert_eq!(stats.median, 3.0);
+    assert_eq!(stats.percentile(25.0), Some(2.0));
+    assert_eq!(stats.histogram.len(), 10);
+    assert_eq!(stats.histogram[0].count, 1);
+    assert_eq!(stats.histogram[2].count, 1);
+    assert_eq!(stats.histogram[9].count, 1);
+    assert_eq!(
+        stats
+            .histogram
+            .iter()
+            .map(|bucket| bucket.count)
+            .sum::<usize>(),
+        5
+    );
+}
+
+#[test]
+fn better_than() {
+    let stats = LeaderboardStats::from(&scores(&[1.0, 2.0, 3.0, 4.0, 5.0])[..]);
+    let value = ScoreValue::Float(4.0);
+    assert_eq!(
+        stats.better_than(value, ScoreOrder::HigherIsBetter),
+        Some(0.75)
+    );
+    assert_eq!(
+        stats.better_than(value, ScoreOrder::LowerIsBetter),
+        Some(0.25)
+    );
+    assert_eq!(
+        stats.better_than(ScoreValue::Float(0.0), ScoreOrder::HigherIsBetter),
+        Some(0.0)
+    );
+    assert_eq!(
+        LeaderboardStats::from(&[][..]).better_than(value, ScoreOrder::HigherIsBetter),
+        None
+    );
+}
+
+#[test]
+fn player_stats_from_scores() {
+    let stats = PlayerStats::from_scores(&scores(&[30.0, 10.0, 20.0]), ScoreOrder::LowerIsBetter);
+    assert_eq!(stats.count, 3);
+    assert_eq!(stats.best, Some(10.0));
+    assert_eq!(stats.mean, Some(20.0));
+    assert_eq!(stats.rank, None);
+    assert_eq!(
+        PlayerStats::from_scores(&[], ScoreOrder::HigherIsBetter),
+        PlayerStats::default()
+    );
+}
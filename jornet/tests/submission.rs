@@ -0,0 +1,20 @@
+use std::collections::HashMap;
+
+use jornet::ScoreSubmission;
+
+#[test]
+fn typed_meta_has_sorted_keys() {
+    let meta: HashMap<&str, HashMap<&str, u32>> = [
+        ("weapon", [("level", 3), ("ammo", 12)].into_iter().collect()),
+        ("armor", [("level", 1)].into_iter().collect()),
+    ]
+    .into_iter()
+    .collect();
+
+    let submission = ScoreSubmission::new(1.0).meta_typed(&meta).unwrap();
+
+    assert_eq!(
+        submission.get_meta(),
+        Some(r#"{"armor":{"level":1},"weapon":{"ammo":12,"level":3}}"#)
+    );
+}
@@ -0,0 +1,42 @@
+use jornet::{Aggregation, Score, ScoreOrder};
+
+mod helper;
+
+fn score(player: &str, team: Option<&str>, value: f32) -> Score {
+    helper::score_with(serde_json::json!({
+        "score": value,
+        "player": player,
+        "team": team,
+    }))
+}
+
+fn scores() -> Vec<Score> {
+    vec![
+        score("alice", Some("red"), 10.0),
+        score("alice", Some("red"), 4.0),
+        score("bob", Some("red"), 2.0),
+        score("carol", Some("blue"), 9.0),
+        score("dave", Some("blue"), 3.0),
+        score("eve", None, 100.0),
+    ]
+}
+
+#[test]
+fn sum_of_best_scores_of_members() {
+    let teams = Aggregation::Sum.aggregate(scores(), ScoreOrder::HigherIsBetter);
+    let teams: Vec<_> = teams
+        .iter()
+        .map(|team| (team.team.as_str(), team.score, team.members, team.rank))
+        .collect();
+    assert_eq!(teams, vec![("blue", 12.0, 2, 1), ("red", 12.0, 2, 1)]);
+}
+
+#[test]
+fn best_and_average() {
+    let best = Aggregation::Best.aggregate(scores(), ScoreOrder::LowerIsBetter);
+    assert_eq!(best[0].team, "red");
+    assert_eq!(best[0].score, 2.0);
+    let average = Aggregation::Average.aggregate(scores(), ScoreOrder::HigherIsBetter);
+    assert_eq!(average[0].team, "blue");
+    assert_eq!(average[0].score, 6.0);
+}
@@ -0,0 +1,38 @@
+use jornet::Score;
+
+mod helper;
+
+fn score_at(timestamp: &str) -> Score {
+    helper::score_with(serde_json::json!({ "timestamp": timestamp }))
+}
+
+#[test]
+fn parse_utc_timestamps() {
+    assert_eq!(score_at("1970-01-01T00:00:00Z").unix_timestamp(), Some(0));
+    assert_eq!(
+        score_at("2022-05-01T12:34:56.789123Z").unix_timestamp(),
+        Some(1_651_408_496)
+    );
+    assert_eq!(
+        score_at("2024-02-29T23:59:59Z").unix_timestamp(),
+        Some(1_709_251_199)
+    );
+}
+
+#[test]
+fn parse_timestamps_with_offset() {
+    assert_eq!(
+        score_at("2022-05-01T14:34:56+02:00").unix_timestamp(),
+        Some(1_651_408_496)
+    );
+    assert_eq!(
+        score_at("2022-05-01T07:04:56-05:30").unix_timestamp(),
+        Some(1_651_408_496)
+    );
+}
+
+#[test]
+fn invalid_timestamps() {
+    assert_eq!(score_at("yesterday").unix_timestamp(), None);
+    assert_eq!(score_at("2022-05-01 12:34:56").unix_timestamp(), None);
+}
@@ -0,0 +1,35 @@
+use helper::{player, score};
+use jornet::{LeaderboardView, ScoreOrder};
+
+mod helper;
+
+#[test]
+fn ranked_with_ties() {
+    let view = LeaderboardView::new(
+        vec![
+            score("a", 1.0),
+            score("b", 3.0),
+            score("c", 3.0),
+            score("d", 2.0),
+        ],
+        ScoreOrder::default(),
+    );
+
+    let ranks: Vec<_> = view
+        .iter()
+        .map(|(rank, score)| (rank, score.player.as_str()))
+        .collect();
+    assert_eq!(ranks, vec![(1, "b"), (1, "c"), (3, "d"), (4, "a")]);
+    assert!(view.is_tied(0));
+    assert!(view.is_tied(1));
+    assert!(!view.is_tied(2));
+}
+
+#[test]
+fn position_of_player() {
+    let view = LeaderboardView::new(
+        vec![score("a", 1.0), score("b", 3.0), score("a", 2.0)],
+        ScoreOrder::default(),
+    );
+    assert_eq!(view.position_of(&player("a")), Some(1));
+}